@@ -0,0 +1,193 @@
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    List,
+    Run {
+        day: u32,
+        part: Option<u32>,
+        input: Option<String>,
+    },
+    Bench {
+        day: u32,
+        iterations: usize,
+        input: Option<String>,
+        json: Option<String>,
+        history: String,
+        threshold: u32,
+    },
+}
+
+pub const DEFAULT_ITERATIONS: usize = 100;
+pub const DEFAULT_HISTORY: &str = "bench-history.jsonl";
+/// percent
+pub const DEFAULT_THRESHOLD: u32 = 10;
+
+pub const USAGE: &str = "usage:
+    aoc list
+    aoc run --day <N> [--part <1|2>] [--input <PATH>]
+    aoc bench --day <N> [--iterations <K>] [--input <PATH>] [--json <OUT>]
+              [--history <PATH>] [--threshold <PERCENT>]";
+
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut iter = args.iter();
+    let command = iter.next().ok_or("missing command")?;
+
+    match command.as_str() {
+        "list" => match iter.next() {
+            Some(arg) => Err(format!("unexpected argument: {}", arg)),
+            None => Ok(Command::List),
+        },
+        "run" | "bench" => {
+            let mut day: Option<u32> = None;
+            let mut part: Option<u32> = None;
+            let mut input: Option<String> = None;
+            let mut iterations: usize = DEFAULT_ITERATIONS;
+            let mut json: Option<String> = None;
+            let mut history = DEFAULT_HISTORY.to_string();
+            let mut threshold = DEFAULT_THRESHOLD;
+
+            while let Some(flag) = iter.next() {
+                let value = iter.next().ok_or(format!("missing value for {}", flag))?;
+                match (command.as_str(), flag.as_str()) {
+                    (_, "--day" | "-d") => day = Some(parse_number(flag, value)?),
+                    (_, "--input" | "-i") => input = Some(value.to_string()),
+                    ("run", "--part" | "-p") => part = Some(parse_number(flag, value)?),
+                    ("bench", "--iterations" | "-n") => {
+                        iterations = match parse_number(flag, value)? {
+                            0 => return Err(format!("{} must be at least 1", flag)),
+                            n => n as usize,
+                        }
+                    }
+                    ("bench", "--json") => json = Some(value.to_string()),
+                    ("bench", "--history") => history = value.to_string(),
+                    ("bench", "--threshold") => threshold = parse_number(flag, value)?,
+                    _ => return Err(format!("unknown flag: {}", flag)),
+                }
+            }
+
+            let day = day.ok_or("missing --day")?;
+            match command.as_str() {
+                "run" => Ok(Command::Run { day, part, input }),
+                _ => Ok(Command::Bench {
+                    day,
+                    iterations,
+                    input,
+                    json,
+                    history,
+                    threshold,
+                }),
+            }
+        }
+        _ => Err(format!("unknown command: {}", command)),
+    }
+}
+
+fn parse_number(flag: &str, value: &str) -> Result<u32, String> {
+    value
+        .parse::<u32>()
+        .map_err(|_| format!("{} expects a number, got \"{}\"", flag, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_run_test() {
+        assert_eq!(
+            parse_args(&args("run --day 5 --part 2 --input foo.txt")),
+            Ok(Command::Run {
+                day: 5,
+                part: Some(2),
+                input: Some("foo.txt".to_string())
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run -d 3")),
+            Ok(Command::Run {
+                day: 3,
+                part: None,
+                input: None
+            })
+        );
+    }
+
+    #[test]
+    fn parse_bench_test() {
+        assert_eq!(
+            parse_args(&args(
+                "bench --day 5 -n 20 --json out.json --history h.jsonl --threshold 25"
+            )),
+            Ok(Command::Bench {
+                day: 5,
+                iterations: 20,
+                input: None,
+                json: Some("out.json".to_string()),
+                history: "h.jsonl".to_string(),
+                threshold: 25,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("bench -d 1 -i foo.txt")),
+            Ok(Command::Bench {
+                day: 1,
+                iterations: DEFAULT_ITERATIONS,
+                input: Some("foo.txt".to_string()),
+                json: None,
+                history: DEFAULT_HISTORY.to_string(),
+                threshold: DEFAULT_THRESHOLD,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("bench --day 1 --iterations 0")),
+            Err("--iterations must be at least 1".to_string())
+        );
+        assert_eq!(
+            parse_args(&args("bench --day 1 --part 2")),
+            Err("unknown flag: --part".to_string())
+        );
+        assert_eq!(
+            parse_args(&args("run --day 1 --json out.json")),
+            Err("unknown flag: --json".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_list_test() {
+        assert_eq!(parse_args(&args("list")), Ok(Command::List));
+        assert_eq!(
+            parse_args(&args("list --day 1")),
+            Err("unexpected argument: --day".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        assert_eq!(parse_args(&args("")), Err("missing command".to_string()));
+        assert_eq!(
+            parse_args(&args("solve")),
+            Err("unknown command: solve".to_string())
+        );
+        assert_eq!(
+            parse_args(&args("run --part 1")),
+            Err("missing --day".to_string())
+        );
+        assert_eq!(
+            parse_args(&args("run --day")),
+            Err("missing value for --day".to_string())
+        );
+        assert_eq!(
+            parse_args(&args("run --day five")),
+            Err("--day expects a number, got \"five\"".to_string())
+        );
+        assert_eq!(
+            parse_args(&args("run --day 1 --verbose yes")),
+            Err("unknown flag: --verbose".to_string())
+        );
+    }
+}
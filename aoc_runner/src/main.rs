@@ -0,0 +1,152 @@
+mod cli;
+mod registry;
+
+use std::{
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_shared::{
+    append_history, find_regressions, format_duration, load_history, try_read_file, AocError,
+    HistoryEntry,
+};
+use cli::{parse_args, Command, USAGE};
+use registry::{days, find_day};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = match parse_args(&args) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::List => {
+            for day in days() {
+                let input = (day.main_input)().unwrap_or_default();
+                println!("day {:>2}  {}", day.day, input);
+            }
+            Ok(())
+        }
+        Command::Run { day, part, input } => {
+            let day = find_day(day).ok_or(format!("day {} is not registered", day))?;
+            let input = match input {
+                Some(i) => i,
+                None => (day.main_input)().ok_or("could not resolve default input path")?,
+            };
+            let content = read_input(&input).map_err(|e| e.to_string())?;
+            let parts = match part {
+                Some(p) => vec![p],
+                None => vec![1, 2],
+            };
+            for part in parts {
+                let answer = (day.solve)(&content, part)
+                    .map_err(|e| format!("day {}: {}", day.day, e.with_path(&input)))?;
+                println!("day {} part {}: {}", day.day, part, answer);
+            }
+            Ok(())
+        }
+        Command::Bench {
+            day,
+            iterations,
+            input,
+            json,
+            history,
+            threshold,
+        } => {
+            let day = find_day(day).ok_or(format!("day {} is not registered", day))?;
+            let input = match input {
+                Some(i) => i,
+                None => (day.main_input)().ok_or("could not resolve default input path")?,
+            };
+            let content = read_input(&input).map_err(|e| e.to_string())?;
+            let report = (day.bench)(&content, iterations)
+                .map_err(|e| format!("day {}: {}", day.day, e.with_path(&input)))?;
+
+            println!(
+                "day {} ({}, {} iterations)",
+                day.day, input, report.iterations
+            );
+            println!(
+                "{:<6} {:>10} {:>10} {:>10}",
+                "stage", "min", "median", "p95"
+            );
+            for (stage, stats) in report.stages() {
+                println!(
+                    "{:<6} {:>10} {:>10} {:>10}",
+                    stage,
+                    format_duration(stats.min_ns),
+                    format_duration(stats.median_ns),
+                    format_duration(stats.p95_ns)
+                );
+            }
+
+            if let Some(out) = json {
+                std::fs::write(&out, report.to_json() + "\n")
+                    .map_err(|e| format!("{}: {}", out, e))?;
+                println!("wrote {}", out);
+            }
+
+            // compare against the history before recording this run, so a run
+            // is never its own baseline
+            let previous = load_history(&history).map_err(|e| e.to_string())?;
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let entries =
+                HistoryEntry::from_report(day.day, &content, git_revision(), timestamp, &report);
+            let regressions = find_regressions(&previous, &entries, threshold as f64 / 100.0);
+            append_history(&history, &entries).map_err(|e| e.to_string())?;
+
+            if regressions.is_empty() {
+                return Ok(());
+            }
+            for regression in &regressions {
+                println!("REGRESSION {}", regression);
+            }
+            Err(format!(
+                "{} stage(s) regressed by more than {}%",
+                regressions.len(),
+                threshold
+            ))
+        }
+    }
+}
+
+/// The puzzle input at `path`, which must not be empty: an empty file is
+/// much more likely a missing download than a real input.
+fn read_input(path: &str) -> Result<String, AocError> {
+    let content = try_read_file(path)?;
+    if content.trim().is_empty() {
+        return Err(AocError::EmptyInput {
+            path: path.to_string(),
+        });
+    }
+    Ok(content)
+}
+
+fn git_revision() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let revision = String::from_utf8(output.stdout).ok()?;
+    Some(revision.trim().to_string())
+}
@@ -0,0 +1,88 @@
+use aoc_shared::{bench, solve, AocError, BenchReport, Solution};
+
+pub struct Day {
+    pub day: u32,
+    pub main_input: fn() -> Option<String>,
+    pub solve: fn(&str, u32) -> Result<String, AocError>,
+    pub bench: fn(&str, usize) -> Result<BenchReport, AocError>,
+}
+
+impl Day {
+    fn new<S: Solution>(day: u32, main_input: fn() -> Option<String>) -> Day {
+        Day {
+            day,
+            main_input,
+            solve: solve::<S>,
+            bench: bench::<S>,
+        }
+    }
+}
+
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::new::<day_1::Day1>(1, day_1::main_input),
+        Day::new::<day_2::Day2>(2, day_2::main_input),
+        Day::new::<day_3::Day3>(3, day_3::main_input),
+        Day::new::<day_4::Day4>(4, day_4::main_input),
+        Day::new::<day_5::Day5>(5, day_5::main_input),
+        Day::new::<day_6::Day6>(6, day_6::main_input),
+        Day::new::<day_7::Day7>(7, day_7::main_input),
+    ]
+}
+
+pub fn find_day(day: u32) -> Option<Day> {
+    days().into_iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::read_file;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn days_are_sorted_and_unique_test() {
+        let numbers: Vec<u32> = days().iter().map(|d| d.day).collect();
+        assert_eq!(numbers, (1..=7).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn main_inputs_exist_test() {
+        for day in days() {
+            let path = (day.main_input)().unwrap();
+            assert!(std::path::Path::new(&path).exists(), "missing {}", path);
+        }
+    }
+
+    #[test]
+    fn solve_day3_test() {
+        let day = find_day(3).unwrap();
+        let input = read_file(&(day.main_input)().unwrap()).unwrap();
+        assert_eq!((day.solve)(&input, 1).unwrap(), "507214");
+        assert_eq!((day.solve)(&input, 2).unwrap(), "72553319");
+        assert_eq!((day.solve)(&input, 3).unwrap_err().to_string(), "no part 3");
+    }
+
+    #[test]
+    fn bench_day7_test() {
+        let day = find_day(7).unwrap();
+        let input = read_file(&(day.main_input)().unwrap()).unwrap();
+        let report = (day.bench)(&input, 3).unwrap();
+        assert_eq!(report.iterations, 3);
+        for (_, stats) in report.stages() {
+            assert!(stats.min_ns <= stats.median_ns);
+            assert!(stats.median_ns <= stats.p95_ns);
+        }
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let day = find_day(7).unwrap();
+        let err = (day.solve)("32T3K 765\nT55J5 6B4\n", 1).unwrap_err();
+        assert_eq!(
+            err.with_path("hands.txt").to_string(),
+            "hands.txt:2:7: expected a bet, found \"6B4\""
+        );
+    }
+}
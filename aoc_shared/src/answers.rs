@@ -0,0 +1,349 @@
+use std::{fmt, path::Path};
+
+use crate::{solve, try_read_file, AocError, Solution};
+
+/// Marker for answers that have not been verified yet. The solver still runs
+/// against them: any answer is accepted, but an error fails the check.
+pub const PENDING: &str = "pending";
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Answer {
+    Known(String),
+    Pending,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Expected {
+    pub input: String,
+    pub part: u32,
+    pub answer: Answer,
+}
+
+/// Reads an answers file, a TOML document with one table per input file
+/// (relative to the answers file) holding `part1` and/or `part2`:
+///
+/// ```toml
+/// ["main.txt"]
+/// part1 = 507214
+/// part2 = "pending"
+/// ```
+pub fn parse_answers(content: &str) -> Result<Vec<Expected>, AocError> {
+    // 1-based (line, column) of a byte offset
+    let position = |offset: usize| {
+        let before = &content[..offset.min(content.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        (line, column)
+    };
+    // semantic errors point at the table header of the offending input
+    let error_in = |input: &str, message: String| {
+        let header = format!("[\"{}\"]", input);
+        let (line, column) = position(content.find(&header).unwrap_or(0));
+        AocError::Parse {
+            path: None,
+            line,
+            column,
+            message,
+        }
+    };
+
+    let table: toml::Table = content.parse().map_err(|e: toml::de::Error| {
+        let (line, column) = position(e.span().map(|s| s.start).unwrap_or(0));
+        AocError::Parse {
+            path: None,
+            line,
+            column,
+            message: e.message().to_string(),
+        }
+    })?;
+
+    let mut expected = Vec::<Expected>::new();
+    for (input, parts) in table {
+        let parts = match parts {
+            toml::Value::Table(parts) => parts,
+            _ => {
+                let message = format!("\"{}\" should be a table of answers", input);
+                return Err(error_in(&input, message));
+            }
+        };
+        for (key, value) in parts {
+            let part = match key.as_str() {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(error_in(&input, format!("unknown key: {}", key))),
+            };
+            let answer = match value {
+                toml::Value::String(s) if s == PENDING => Answer::Pending,
+                toml::Value::String(s) => Answer::Known(s),
+                toml::Value::Integer(n) => Answer::Known(n.to_string()),
+                _ => {
+                    let message = format!("{} should be a number or string", key);
+                    return Err(error_in(&input, message));
+                }
+            };
+            expected.push(Expected {
+                input: input.clone(),
+                part,
+                answer,
+            });
+        }
+    }
+    Ok(expected)
+}
+
+pub fn load_answers(path: &str) -> Result<Vec<Expected>, AocError> {
+    parse_answers(&try_read_file(path)?).map_err(|e| e.with_path(path))
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Outcome {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    Pending,
+    /// The answer is pending and the solver returned an error.
+    Failed,
+}
+
+#[derive(Debug)]
+pub struct Checked {
+    pub input: String,
+    pub part: u32,
+    pub got: Result<String, String>,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Checked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let got = match &self.got {
+            Ok(answer) => answer.clone(),
+            Err(e) => format!("error: {}", e),
+        };
+        match &self.outcome {
+            Outcome::Correct => write!(f, "ok       {} part {}: {}", self.input, self.part, got),
+            Outcome::Pending => write!(
+                f,
+                "pending  {} part {}: {} (not verified)",
+                self.input, self.part, got
+            ),
+            Outcome::Failed => write!(
+                f,
+                "FAILED   {} part {}: {} (answer pending)",
+                self.input, self.part, got
+            ),
+            Outcome::Wrong { expected } => write!(
+                f,
+                "WRONG    {} part {}: expected {}, got {}",
+                self.input, self.part, expected, got
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AnswerReport {
+    pub path: String,
+    pub results: Vec<Checked>,
+}
+
+impl AnswerReport {
+    pub fn failures(&self) -> Vec<&Checked> {
+        self.results
+            .iter()
+            .filter(|c| matches!(c.outcome, Outcome::Wrong { .. } | Outcome::Failed))
+            .collect()
+    }
+
+    pub fn pending(&self) -> Vec<&Checked> {
+        self.results
+            .iter()
+            .filter(|c| c.outcome == Outcome::Pending)
+            .collect()
+    }
+
+    /// Prints every result and panics if any registered answer was wrong, or
+    /// a pending one failed.
+    pub fn assert_ok(&self) {
+        for checked in &self.results {
+            println!("{}", checked);
+        }
+        let failures = self.failures();
+        if !failures.is_empty() {
+            let lines: Vec<String> = failures.iter().map(|c| c.to_string()).collect();
+            panic!(
+                "{} of {} answers in {} are wrong:\n{}",
+                failures.len(),
+                self.results.len(),
+                self.path,
+                lines.join("\n")
+            );
+        }
+    }
+}
+
+/// Runs `S` against every input registered in the answers file at `path`.
+pub fn check_answers<S: Solution>(path: &str) -> Result<AnswerReport, AocError> {
+    let dir = Path::new(path).parent().unwrap_or(Path::new(""));
+    let mut results = Vec::<Checked>::new();
+
+    for expected in load_answers(path)? {
+        let input_path = dir.join(&expected.input).to_string_lossy().to_string();
+        let got = try_read_file(&input_path)
+            .and_then(|content| solve::<S>(&content, expected.part))
+            .map_err(|e| e.with_path(&input_path).to_string());
+        let outcome = match (&expected.answer, &got) {
+            (Answer::Pending, Ok(_)) => Outcome::Pending,
+            (Answer::Pending, Err(_)) => Outcome::Failed,
+            (Answer::Known(answer), Ok(g)) if answer == g => Outcome::Correct,
+            (Answer::Known(answer), _) => Outcome::Wrong {
+                expected: answer.clone(),
+            },
+        };
+        results.push(Checked {
+            input: expected.input,
+            part: expected.part,
+            got,
+            outcome,
+        });
+    }
+
+    Ok(AnswerReport {
+        path: path.to_string(),
+        results,
+    })
+}
+
+/// Generates an `answers_test` checking `$solution` against the crate's
+/// `resources/answers.toml`.
+#[macro_export]
+macro_rules! answers_test {
+    ($solution:ty) => {
+        #[test]
+        fn answers_test() {
+            let path = $crate::try_resource_path!("answers.toml").unwrap();
+            match $crate::check_answers::<$solution>(&path) {
+                Ok(report) => report.assert_ok(),
+                Err(e) => panic!("{}", e),
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures::SumLines, resource_path};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_answers_test() {
+        let content = concat!(
+            "[\"main.txt\"]\n",
+            "part1 = 507214\n",
+            "part2 = \"pending\"\n",
+            "\n",
+            "[\"sample.txt\"]\n",
+            "part2 = \"abc\"\n",
+        );
+        assert_eq!(
+            parse_answers(content).unwrap(),
+            vec![
+                Expected {
+                    input: "main.txt".to_string(),
+                    part: 1,
+                    answer: Answer::Known("507214".to_string())
+                },
+                Expected {
+                    input: "main.txt".to_string(),
+                    part: 2,
+                    answer: Answer::Pending
+                },
+                Expected {
+                    input: "sample.txt".to_string(),
+                    part: 2,
+                    answer: Answer::Known("abc".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_answers_errors_test() {
+        let error = |content: &str| parse_answers(content).unwrap_err().to_string();
+        assert_eq!(
+            error("[\"sample.txt\"]\npart1 = 1\n\n[\"main.txt\"]\npart3 = 1\n"),
+            "4:1: unknown key: part3"
+        );
+        assert_eq!(
+            error("[\"main.txt\"]\npart1 = 1.5\n"),
+            "1:1: part1 should be a number or string"
+        );
+        assert_eq!(
+            error("part1 = 1\n"),
+            "1:1: \"part1\" should be a table of answers"
+        );
+        // syntax errors carry toml's own message at the offending position
+        assert!(error("[\"main.txt\"]\npart1 = = 1\n").starts_with("2:9: "));
+    }
+
+    #[test]
+    fn check_answers_test() {
+        let path = resource_path!("answers/answers.toml").unwrap();
+        let report = check_answers::<SumLines>(&path).unwrap();
+        let summary: Vec<(String, u32, Outcome)> = report
+            .results
+            .iter()
+            .map(|c| (c.input.clone(), c.part, c.outcome.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "bad.txt".to_string(),
+                    1,
+                    Outcome::Wrong {
+                        expected: "1".to_string()
+                    }
+                ),
+                ("missing.txt".to_string(), 1, Outcome::Failed),
+                ("numbers.txt".to_string(), 1, Outcome::Correct),
+                (
+                    "numbers.txt".to_string(),
+                    2,
+                    Outcome::Wrong {
+                        expected: "4".to_string()
+                    }
+                ),
+                ("pending.txt".to_string(), 1, Outcome::Pending),
+            ]
+        );
+        assert_eq!(report.failures().len(), 3);
+        assert_eq!(report.pending().len(), 1);
+        assert_eq!(
+            report.results[3].to_string(),
+            "WRONG    numbers.txt part 2: expected 4, got 3"
+        );
+        assert!(report.results[0]
+            .to_string()
+            .ends_with("bad.txt:2:1: expected a number, found \"x\""));
+        assert_eq!(
+            report.results[1].to_string(),
+            format!(
+                "FAILED   missing.txt part 1: error: {}: file not found (answer pending)",
+                path.replace("answers.toml", "missing.txt")
+            )
+        );
+        assert_eq!(
+            report.results[4].to_string(),
+            "pending  pending.txt part 1: 9 (not verified)"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "3 of 5 answers")]
+    fn assert_ok_test() {
+        let path = resource_path!("answers/answers.toml").unwrap();
+        check_answers::<SumLines>(&path).unwrap().assert_ok();
+    }
+}
@@ -0,0 +1,175 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AocError, Solution};
+
+/// Summary of the timings of one stage, in nanoseconds.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut nanos: Vec<u64> = samples
+            .iter()
+            .map(|d| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX))
+            .collect();
+        nanos.sort();
+
+        // nearest-rank percentile
+        let percentile = |p: usize| match nanos.len() {
+            0 => 0,
+            n => nanos[(n * p).div_ceil(100).max(1) - 1],
+        };
+        Stats {
+            min_ns: nanos.first().cloned().unwrap_or(0),
+            median_ns: percentile(50),
+            p95_ns: percentile(95),
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl BenchReport {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn stages(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// Times `S::parse`, `S::part1` and `S::part2` separately, `iterations` times
+/// each. Parts are timed against a single parsed input.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<BenchReport, AocError> {
+    let iterations = iterations.max(1);
+    let parsed = S::parse(input)?;
+
+    let mut parse = Vec::<Duration>::with_capacity(iterations);
+    let mut part1 = Vec::<Duration>::with_capacity(iterations);
+    let mut part2 = Vec::<Duration>::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        black_box(S::parse(black_box(input))?);
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(black_box(&parsed)));
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(black_box(&parsed)));
+        part2.push(start.elapsed());
+    }
+
+    Ok(BenchReport {
+        iterations,
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+    })
+}
+
+/// Times `iterations` calls of `f`, for comparing implementations that
+/// aren't a whole `Solution`.
+pub fn measure<R>(iterations: usize, mut f: impl FnMut() -> R) -> Stats {
+    let samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+pub fn format_duration(nanos: u64) -> String {
+    match nanos {
+        0..=9_999 => format!("{}ns", nanos),
+        10_000..=9_999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        10_000_000..=9_999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::Sleepy;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn stats_test() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_nanos).collect();
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Stats {
+                min_ns: 1,
+                median_ns: 10,
+                p95_ns: 19
+            }
+        );
+
+        let single = Stats::from_samples(&[Duration::from_nanos(7)]);
+        assert_eq!((single.min_ns, single.median_ns, single.p95_ns), (7, 7, 7));
+
+        let empty = Stats::from_samples(&[]);
+        assert_eq!((empty.min_ns, empty.median_ns, empty.p95_ns), (0, 0, 0));
+    }
+
+    #[test]
+    fn bench_test() {
+        let report = bench::<Sleepy>("2", 3).unwrap();
+        assert_eq!(report.iterations, 3);
+        assert!(report.part1.min_ns >= 2_000_000);
+        assert!(report.part2.min_ns >= 4_000_000);
+        assert!(report.parse.min_ns <= report.parse.median_ns);
+        assert!(report.part2.median_ns <= report.part2.p95_ns);
+
+        let round_trip: BenchReport = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(round_trip, report);
+    }
+
+    #[test]
+    fn measure_test() {
+        let mut calls = 0;
+        let stats = measure(4, || {
+            calls += 1;
+            std::thread::sleep(Duration::from_millis(1));
+        });
+        assert_eq!(calls, 4);
+        assert!(stats.min_ns >= 1_000_000);
+        assert!(stats.median_ns <= stats.p95_ns);
+    }
+
+    #[test]
+    fn format_duration_test() {
+        assert_eq!(format_duration(950), "950ns");
+        assert_eq!(format_duration(12_340), "12.3µs");
+        assert_eq!(format_duration(45_600_000), "45.6ms");
+        assert_eq!(format_duration(12_345_000_000), "12.35s");
+    }
+}
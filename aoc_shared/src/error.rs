@@ -0,0 +1,154 @@
+use std::{error::Error, fmt, io};
+
+#[derive(Debug)]
+pub enum AocError {
+    MissingFile {
+        path: String,
+    },
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    NonUtf8 {
+        path: String,
+        // byte offset of the first invalid sequence, `None` for paths
+        valid_up_to: Option<usize>,
+    },
+    Parse {
+        path: Option<String>,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    EmptyInput {
+        path: String,
+    },
+    UnknownPart {
+        part: u32,
+    },
+}
+
+impl AocError {
+    pub fn io(path: &str, source: io::Error) -> AocError {
+        match source.kind() {
+            io::ErrorKind::NotFound => AocError::MissingFile {
+                path: path.to_string(),
+            },
+            _ => AocError::Io {
+                path: path.to_string(),
+                source,
+            },
+        }
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            AocError::MissingFile { path }
+            | AocError::Io { path, .. }
+            | AocError::NonUtf8 { path, .. }
+            | AocError::EmptyInput { path } => Some(path),
+            AocError::Parse { path, .. } => path.as_deref(),
+            AocError::UnknownPart { .. } => None,
+        }
+    }
+
+    /// Attaches the input path to a parse error, other errors already carry one.
+    pub fn with_path(self, input_path: &str) -> AocError {
+        match self {
+            AocError::Parse {
+                path: None,
+                line,
+                column,
+                message,
+            } => AocError::Parse {
+                path: Some(input_path.to_string()),
+                line,
+                column,
+                message,
+            },
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingFile { path } => write!(f, "{}: file not found", path),
+            AocError::Io { path, source } => write!(f, "{}: {}", path, source),
+            AocError::NonUtf8 {
+                path,
+                valid_up_to: Some(offset),
+            } => write!(f, "{}: invalid UTF-8 at byte {}", path, offset),
+            AocError::NonUtf8 {
+                path,
+                valid_up_to: None,
+            } => write!(f, "{}: path is not valid UTF-8", path),
+            AocError::Parse {
+                path,
+                line,
+                column,
+                message,
+            } => match path {
+                Some(path) => write!(f, "{}:{}:{}: {}", path, line, column, message),
+                None => write!(f, "{}:{}: {}", line, column, message),
+            },
+            AocError::EmptyInput { path } => write!(f, "{}: input is empty", path),
+            AocError::UnknownPart { part } => write!(f, "no part {}", part),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display_test() {
+        let err = AocError::io("a.txt", io::Error::from(io::ErrorKind::NotFound));
+        assert_eq!(err.to_string(), "a.txt: file not found");
+
+        let err = AocError::io("a.txt", io::Error::from(io::ErrorKind::PermissionDenied));
+        assert_eq!(err.to_string(), "a.txt: permission denied");
+        assert!(err.source().is_some());
+
+        let err = AocError::NonUtf8 {
+            path: "a.txt".to_string(),
+            valid_up_to: Some(12),
+        };
+        assert_eq!(err.to_string(), "a.txt: invalid UTF-8 at byte 12");
+
+        let err = AocError::Parse {
+            path: Some("a.txt".to_string()),
+            line: 3,
+            column: 7,
+            message: "expected number".to_string(),
+        };
+        assert_eq!(err.to_string(), "a.txt:3:7: expected number");
+        assert_eq!(err.path(), Some("a.txt"));
+
+        let err = AocError::Parse {
+            path: None,
+            line: 3,
+            column: 7,
+            message: "expected number".to_string(),
+        };
+        assert_eq!(err.to_string(), "3:7: expected number");
+        assert_eq!(err.path(), None);
+
+        let err = AocError::EmptyInput {
+            path: "a.txt".to_string(),
+        };
+        assert_eq!(err.to_string(), "a.txt: input is empty");
+    }
+}
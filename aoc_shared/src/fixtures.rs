@@ -0,0 +1,54 @@
+//! `Solution`s for testing the harness code.
+
+use std::time::Duration;
+
+use crate::{parse_at, AocError, Solution};
+
+/// One number per line: part 1 is their sum, part 2 how many there are.
+pub struct SumLines;
+
+impl Solution for SumLines {
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let numbers = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| parse_at::<i32>(i + 1, l, l, "a number"))
+            .collect::<Result<_, _>>()?;
+        Ok(numbers)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.iter().sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.len()
+    }
+}
+
+/// Sleeps the input's number of milliseconds in part 1, twice that in part 2.
+pub struct Sleepy;
+
+impl Solution for Sleepy {
+    type Input = u64;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.trim().parse().unwrap())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        std::thread::sleep(Duration::from_millis(*input));
+        *input
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        std::thread::sleep(Duration::from_millis(2 * input));
+        *input
+    }
+}
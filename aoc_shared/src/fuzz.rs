@@ -0,0 +1,110 @@
+use std::ops::Range;
+
+/// Small deterministic xorshift64* generator for randomized tests. Not
+/// suitable for anything but producing reproducible test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // xorshift gets stuck on 0
+        let state = seed ^ 0x9e3779b97f4a7c15;
+        Rng {
+            state: if state == 0 { 1 } else { state },
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545f4914f6cdd1d)
+    }
+
+    /// Uniform-ish value in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "empty range {:?}", range);
+        let span = (range.end - range.start) as u64;
+        range.start + (self.next_u64() % span) as usize
+    }
+
+    /// `true` once in `n` calls on average.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.range(0..n) == 0
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+}
+
+/// Greedy shrinking: keeps replacing `case` with the first of its
+/// `candidates` that still `fails`, until none does. Every candidate must be
+/// strictly smaller than the case it comes from, or this never ends.
+pub fn shrink<T, C, F>(mut case: T, candidates: C, mut fails: F) -> T
+where
+    C: Fn(&T) -> Vec<T>,
+    F: FnMut(&T) -> bool,
+{
+    loop {
+        match candidates(&case).into_iter().find(|c| fails(c)) {
+            Some(smaller) => case = smaller,
+            None => return case,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn deterministic_test() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        let first: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let second: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        assert_eq!(first, second);
+        assert!(Rng::new(8).next_u64() != first[0]);
+    }
+
+    #[test]
+    fn range_test() {
+        let mut rng = Rng::new(0);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let n = rng.range(10..16);
+            assert!((10..16).contains(&n));
+            seen[n - 10] = true;
+        }
+        assert_eq!(seen, [true; 6]);
+        assert_eq!(rng.range(3..4), 3);
+        assert!(["a", "b"].contains(rng.choose(&["a", "b"])));
+    }
+
+    #[test]
+    fn shrink_test() {
+        // smallest list still summing to more than 10
+        let candidates = |list: &Vec<u32>| -> Vec<Vec<u32>> {
+            let mut smaller = Vec::new();
+            for i in 0..list.len() {
+                let mut without = list.clone();
+                without.remove(i);
+                smaller.push(without);
+            }
+            for i in 0..list.len() {
+                if list[i] > 0 {
+                    let mut lower = list.clone();
+                    lower[i] -= 1;
+                    smaller.push(lower);
+                }
+            }
+            smaller
+        };
+        let shrunk = shrink(vec![3, 9, 4, 7], candidates, |l| l.iter().sum::<u32>() > 10);
+        assert_eq!(shrunk.iter().sum::<u32>(), 11);
+        assert_eq!(shrunk.len(), 2);
+    }
+}
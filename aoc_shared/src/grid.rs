@@ -0,0 +1,282 @@
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+pub type Pos = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row. Positions are `(x, y)` with `(0, 0)`
+/// the first char of the first line.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid with one row per line and one cell per char. Every line
+    /// must have the same number of chars.
+    pub fn parse<S, F>(lines: &[S], mut cell: F) -> Result<Grid<T>, ParseError>
+    where
+        S: AsRef<str>,
+        F: FnMut(char) -> T,
+    {
+        let width = lines
+            .first()
+            .map(|l| l.as_ref().chars().count())
+            .unwrap_or(0);
+        let mut cells = Vec::<T>::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let line = line.as_ref();
+            let mut len: usize = 0;
+            for c in line.chars() {
+                if len == width {
+                    let found = format!("\"{}\"", c);
+                    return Err(ParseError::new(y + 1, width + 1, "end of row", &found));
+                }
+                cells.push(cell(c));
+                len += 1;
+            }
+            if len < width {
+                let expected = format!("a row of {} cells", width);
+                return Err(ParseError::end_of_line(y + 1, line, &expected));
+            }
+        }
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        match self.contains(pos) {
+            true => Some(&self.cells[pos.1 * self.width + pos.0]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.1 * self.width + pos.0]),
+            false => None,
+        }
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        match y < self.height {
+            true => Some(&self.cells[y * self.width..(y + 1) * self.width]),
+            false => None,
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Cells of column `x` from top to bottom; empty when `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// Orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offsets(pos, &OFFSETS_4)
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offsets(pos, &OFFSETS_8)
+    }
+
+    fn offsets(
+        &self,
+        (x, y): Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + '_ {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let pos = (x.checked_add_signed(*dx)?, y.checked_add_signed(*dy)?);
+            self.contains(pos).then_some(pos)
+        })
+    }
+
+    /// Position of the first cell (row by row) matching `predicate`.
+    pub fn find<P>(&self, mut predicate: P) -> Option<Pos>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().find(|(_, c)| predicate(c)).map(|(pos, _)| pos)
+    }
+
+    /// Positions of every cell matching `predicate`, row by row.
+    pub fn positions<'a, P>(&'a self, mut predicate: P) -> impl Iterator<Item = Pos> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, c)| predicate(c))
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {:?} is outside of a {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {:?} is outside of a {}x{} grid",
+                pos, width, height
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn sample() -> Grid<char> {
+        Grid::parse(&["abc", "def"], |c| c).unwrap()
+    }
+
+    #[test]
+    fn parse_test() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+
+        let digits = Grid::parse(&["12".to_string(), "34".to_string()], |c| {
+            c.to_digit(10).unwrap()
+        })
+        .unwrap();
+        assert_eq!(digits.iter().map(|(_, d)| *d).sum::<u32>(), 10);
+
+        let empty = Grid::<char>::parse::<&str, _>(&[], |c| c).unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.get((0, 0)), None);
+    }
+
+    #[test]
+    fn parse_ragged_test() {
+        assert_eq!(
+            Grid::parse(&["abc", "de"], |c| c).unwrap_err().to_string(),
+            "2:3: expected a row of 3 cells, found end of line"
+        );
+        assert_eq!(
+            Grid::parse(&["abc", "defg"], |c| c)
+                .unwrap_err()
+                .to_string(),
+            "2:4: expected end of row, found \"g\""
+        );
+    }
+
+    #[test]
+    fn get_test() {
+        let mut grid = sample();
+        assert_eq!(grid.get((0, 0)), Some(&'a'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.get((usize::MAX, 0)), None);
+
+        *grid.get_mut((1, 1)).unwrap() = 'E';
+        grid[(0, 0)] = 'A';
+        assert_eq!(grid.row(1), Some(&['d', 'E', 'f'][..]));
+        assert_eq!(grid.row(0), Some(&['A', 'b', 'c'][..]));
+        assert_eq!(grid.row(2), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "position (3, 0) is outside of a 3x2 grid")]
+    fn index_out_of_bounds_test() {
+        let _ = sample()[(3, 0)];
+    }
+
+    #[test]
+    fn rows_and_columns_test() {
+        let grid = sample();
+        let rows: Vec<String> = grid.rows().map(|r| r.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn neighbors_test() {
+        let grid = Grid::parse(&["abc", "def", "ghi"], |c| c).unwrap();
+        let at = |positions: Vec<Pos>| -> String { positions.iter().map(|p| grid[*p]).collect() };
+
+        assert_eq!(at(grid.neighbors4((1, 1)).collect()), "bdfh");
+        assert_eq!(at(grid.neighbors8((1, 1)).collect()), "abcdfghi");
+        assert_eq!(at(grid.neighbors4((0, 0)).collect()), "bd");
+        assert_eq!(at(grid.neighbors8((0, 0)).collect()), "bde");
+        assert_eq!(at(grid.neighbors8((2, 2)).collect()), "efh");
+        assert_eq!(grid.neighbors8((5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn find_test() {
+        let grid = Grid::parse(&["a.b", "..a"], |c| c).unwrap();
+        assert_eq!(grid.find(|c| *c == 'a'), Some((0, 0)));
+        assert_eq!(grid.find(|c| *c == 'z'), None);
+        assert_eq!(
+            grid.positions(|c| *c != '.').collect::<Vec<Pos>>(),
+            vec![(0, 0), (2, 0), (2, 1)]
+        );
+    }
+}
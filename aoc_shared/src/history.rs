@@ -0,0 +1,292 @@
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::Write,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AocError, BenchReport, Stats};
+
+/// One line of a benchmark history file: the timings of a single stage
+/// (`parse`, `part1` or `part2`) of one day on one input.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub day: u32,
+    pub part: String,
+    pub input_hash: String,
+    pub revision: Option<String>,
+    pub timestamp: u64,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+impl HistoryEntry {
+    pub fn from_report(
+        day: u32,
+        input: &str,
+        revision: Option<String>,
+        timestamp: u64,
+        report: &BenchReport,
+    ) -> Vec<HistoryEntry> {
+        let input_hash = input_hash(input);
+        report
+            .stages()
+            .iter()
+            .map(|(part, stats)| HistoryEntry {
+                day,
+                part: part.to_string(),
+                input_hash: input_hash.clone(),
+                revision: revision.clone(),
+                timestamp,
+                iterations: report.iterations,
+                stats: (*stats).clone(),
+            })
+            .collect()
+    }
+
+    fn same_run(&self, other: &HistoryEntry) -> bool {
+        self.day == other.day && self.part == other.part && self.input_hash == other.input_hash
+    }
+}
+
+/// 64-bit FNV-1a of the input, so the history can tell inputs apart without
+/// storing them.
+pub fn input_hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+pub fn parse_history(content: &str) -> Result<Vec<HistoryEntry>, AocError> {
+    let mut entries = Vec::<HistoryEntry>::new();
+    for (i, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(line).map_err(|e| AocError::Parse {
+            path: None,
+            line: i + 1,
+            column: e.column(),
+            message: e.to_string(),
+        })?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// Reads a JSON-lines history file; a file that doesn't exist yet is an
+/// empty history.
+pub fn load_history(path: &str) -> Result<Vec<HistoryEntry>, AocError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(AocError::io(path, e)),
+    };
+    parse_history(&content).map_err(|e| e.with_path(path))
+}
+
+pub fn append_history(path: &str, entries: &[HistoryEntry]) -> Result<(), AocError> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| AocError::io(path, e))?;
+    for entry in entries {
+        let line = serde_json::to_string(entry).unwrap();
+        writeln!(file, "{}", line).map_err(|e| AocError::io(path, e))?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Regression {
+    pub day: u32,
+    pub part: String,
+    pub best_ns: u64,
+    pub best_revision: Option<String>,
+    pub current_ns: u64,
+}
+
+impl Regression {
+    pub fn slowdown(&self) -> f64 {
+        self.current_ns as f64 / self.best_ns.max(1) as f64 - 1.0
+    }
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} {}: median {}ns is {:.1}% slower than the best {}ns",
+            self.day,
+            self.part,
+            self.current_ns,
+            self.slowdown() * 100.0,
+            self.best_ns
+        )?;
+        if let Some(revision) = &self.best_revision {
+            write!(f, " ({})", revision)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares the median of every entry in `current` with the best median
+/// recorded in `history` for the same day, part and input. Anything more than
+/// `threshold` (a fraction, 0.1 = 10%) slower is a regression. Entries with
+/// no history can't regress.
+pub fn find_regressions(
+    history: &[HistoryEntry],
+    current: &[HistoryEntry],
+    threshold: f64,
+) -> Vec<Regression> {
+    let mut regressions = Vec::<Regression>::new();
+    for entry in current {
+        let best = history
+            .iter()
+            .filter(|h| h.same_run(entry))
+            .min_by_key(|h| h.stats.median_ns);
+        let best = match best {
+            Some(best) => best,
+            None => continue,
+        };
+        let limit = best.stats.median_ns as f64 * (1.0 + threshold);
+        if entry.stats.median_ns as f64 > limit {
+            regressions.push(Regression {
+                day: entry.day,
+                part: entry.part.clone(),
+                best_ns: best.stats.median_ns,
+                best_revision: best.revision.clone(),
+                current_ns: entry.stats.median_ns,
+            });
+        }
+    }
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::try_resource_path;
+    use pretty_assertions::assert_eq;
+
+    fn entry(
+        day: u32,
+        part: &str,
+        input_hash: &str,
+        revision: &str,
+        median_ns: u64,
+    ) -> HistoryEntry {
+        HistoryEntry {
+            day,
+            part: part.to_string(),
+            input_hash: input_hash.to_string(),
+            revision: Some(revision.to_string()),
+            timestamp: 0,
+            iterations: 10,
+            stats: Stats {
+                min_ns: median_ns,
+                median_ns,
+                p95_ns: median_ns,
+            },
+        }
+    }
+
+    #[test]
+    fn input_hash_test() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert!(input_hash("main.txt") != input_hash("main.txt\n"));
+    }
+
+    #[test]
+    fn from_report_test() {
+        let stats = |n| Stats {
+            min_ns: n,
+            median_ns: n,
+            p95_ns: n,
+        };
+        let report = BenchReport {
+            iterations: 10,
+            parse: stats(1),
+            part1: stats(2),
+            part2: stats(3),
+        };
+        let entries = HistoryEntry::from_report(3, "abc", Some("abc123".to_string()), 42, &report);
+        let parts: Vec<(&str, u64)> = entries
+            .iter()
+            .map(|e| (e.part.as_str(), e.stats.median_ns))
+            .collect();
+        assert_eq!(parts, vec![("parse", 1), ("part1", 2), ("part2", 3)]);
+        assert!(entries.iter().all(|e| e.input_hash == input_hash("abc")));
+    }
+
+    #[test]
+    fn find_regressions_test() {
+        let history = vec![
+            entry(3, "part1", "h", "aaa", 1000),
+            entry(3, "part1", "h", "bbb", 800),
+            entry(3, "part1", "other", "ccc", 100),
+            entry(3, "part2", "h", "aaa", 500),
+        ];
+        let current = vec![
+            entry(3, "part1", "h", "ddd", 900),
+            entry(3, "part2", "h", "ddd", 540),
+            entry(3, "parse", "h", "ddd", 99999),
+        ];
+
+        // part1 is 12.5% slower than the best run, part2 only 8%
+        let regressions = find_regressions(&history, &current, 0.1);
+        assert_eq!(
+            regressions,
+            vec![Regression {
+                day: 3,
+                part: "part1".to_string(),
+                best_ns: 800,
+                best_revision: Some("bbb".to_string()),
+                current_ns: 900,
+            }]
+        );
+        assert_eq!(
+            regressions[0].to_string(),
+            "day 3 part1: median 900ns is 12.5% slower than the best 800ns (bbb)"
+        );
+
+        assert_eq!(find_regressions(&history, &current, 0.2), vec![]);
+        assert_eq!(find_regressions(&history, &current, 0.05).len(), 2);
+    }
+
+    #[test]
+    fn history_file_test() {
+        let path = std::env::temp_dir().join(format!("aoc_history_{}.jsonl", std::process::id()));
+        let path = path.to_str().unwrap();
+        let _ = fs::remove_file(path);
+
+        assert_eq!(load_history(path).unwrap(), vec![]);
+        let first = vec![entry(1, "part1", "h", "aaa", 10)];
+        let second = vec![
+            entry(1, "part2", "h", "bbb", 20),
+            entry(2, "parse", "h", "bbb", 30),
+        ];
+        append_history(path, &first).unwrap();
+        append_history(path, &second).unwrap();
+        assert_eq!(load_history(path).unwrap(), [first, second].concat());
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn bad_history_test() {
+        let path = try_resource_path!("history/bad.jsonl").unwrap();
+        let err = load_history(&path).unwrap_err().to_string();
+        assert!(
+            err.starts_with(&format!("{}:2:", path)),
+            "unexpected error: {}",
+            err
+        );
+    }
+}
@@ -0,0 +1,546 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Overflow, Unsigned};
+
+/// Where an `Interval` stops, exclusive. `PastMax` is the value after
+/// `T::MAX`, which `T` can't hold, so that intervals can include `T::MAX`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum End<T> {
+    At(T),
+    PastMax,
+}
+
+impl<T: Unsigned> End<T> {
+    /// `None` for `PastMax`.
+    pub fn value(self) -> Option<T> {
+        match self {
+            End::At(value) => Some(value),
+            End::PastMax => None,
+        }
+    }
+}
+
+/// The half-open range of values `start..end`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Interval<T = usize> {
+    pub start: T,
+    pub end: End<T>,
+}
+
+impl<T: Unsigned> Interval<T> {
+    pub fn new(start: T, end: T) -> Interval<T> {
+        assert!(
+            start <= end,
+            "interval {}..{} ends before it starts",
+            start,
+            end
+        );
+        Interval {
+            start,
+            end: End::At(end),
+        }
+    }
+
+    /// `start..=last`, which unlike `new` can include `T::MAX`.
+    pub fn inclusive(start: T, last: T) -> Interval<T> {
+        assert!(
+            start <= last,
+            "interval {}..={} ends before it starts",
+            start,
+            last
+        );
+        let end = match last.checked_add(T::ONE) {
+            Some(end) => End::At(end),
+            None => End::PastMax,
+        };
+        Interval { start, end }
+    }
+
+    /// Panics if the interval doesn't fit in `T`, see `try_from_len`.
+    pub fn from_len(start: T, len: T) -> Interval<T> {
+        match Interval::try_from_len(start, len) {
+            Ok(interval) => interval,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Fails if the last value, `start + len - 1`, overflows.
+    pub fn try_from_len(start: T, len: T) -> Result<Interval<T>, Overflow> {
+        if len == T::ZERO {
+            return Ok(Interval::new(start, start));
+        }
+        match start.checked_add(len - T::ONE) {
+            Some(last) => Ok(Interval::inclusive(start, last)),
+            None => Err(Overflow::new::<T>(format!("{} + {}", start, len))),
+        }
+    }
+
+    /// Panics if the interval holds every value of `T`, one more than
+    /// `T::MAX`.
+    pub fn len(&self) -> T {
+        match self.end {
+            End::At(end) => end - self.start,
+            End::PastMax => match (T::MAX - self.start).checked_add(T::ONE) {
+                Some(len) => len,
+                None => panic!("interval {} has more than {}::MAX values", self, T::NAME),
+            },
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end == End::At(self.start)
+    }
+
+    /// The largest value in the interval, which must not be empty.
+    pub fn last(&self) -> T {
+        assert!(!self.is_empty(), "interval {} is empty", self);
+        match self.end {
+            End::At(end) => end - T::ONE,
+            End::PastMax => T::MAX,
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        value >= self.start && End::At(value) < self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Common part of both intervals, `None` when it is empty.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        match End::At(start) < end {
+            true => Some(Interval { start, end }),
+            false => None,
+        }
+    }
+
+    /// The parts of `self` left and right of `other`, each `None` when empty.
+    pub fn difference(&self, other: &Interval<T>) -> (Option<Interval<T>>, Option<Interval<T>>) {
+        let left = self.intersection(&Interval::new(T::ZERO, other.start));
+        let right = match other.end {
+            End::At(end) => self.intersection(&Interval::inclusive(end, T::MAX)),
+            End::PastMax => None,
+        };
+        (left, right)
+    }
+
+    /// Moves the interval so that the value `from` lands on `to`. `from` must
+    /// not be greater than `start`. Panics on overflow, see `try_shift`.
+    pub fn shift(&self, from: T, to: T) -> Interval<T> {
+        match self.try_shift(from, to) {
+            Ok(interval) => interval,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_shift(&self, from: T, to: T) -> Result<Interval<T>, Overflow> {
+        let start = to.try_add(self.start.try_sub(from)?)?;
+        if self.is_empty() {
+            return Ok(Interval::new(start, start));
+        }
+        let last = to.try_add(self.last() - from)?;
+        Ok(Interval::inclusive(start, last))
+    }
+}
+
+impl<T: Unsigned> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.end {
+            End::At(end) => write!(f, "{}..{}", self.start, end),
+            End::PastMax => write!(f, "{}..={}", self.start, T::MAX),
+        }
+    }
+}
+
+/// A set of values stored as sorted, disjoint intervals. Overlapping or
+/// touching intervals are merged on insertion, so the number of intervals
+/// only grows with the number of actual gaps.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct IntervalSet<T = usize> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Unsigned> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet { intervals: vec![] }
+    }
+}
+
+impl<T: Unsigned> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn count(&self) -> T {
+        self.intervals.iter().fold(T::ZERO, |acc, i| acc + i.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|i| i.last())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= End::At(value));
+        self.intervals.get(index).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // everything in first..last touches or overlaps `interval`
+        let first = self
+            .intervals
+            .partition_point(|i| i.end < End::At(interval.start));
+        let last = self
+            .intervals
+            .partition_point(|i| End::At(i.start) <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |acc, i| Interval {
+                start: acc.start.min(i.start),
+                end: acc.end.max(i.end),
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut set = self.clone();
+        for interval in other.iter() {
+            set.insert(*interval);
+        }
+        set
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::<Interval<T>>::new();
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (left, right) = (self.intervals[a], other.intervals[b]);
+            if let Some(common) = left.intersection(&right) {
+                intervals.push(common);
+            }
+            match left.end < right.end {
+                true => a += 1,
+                false => b += 1,
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::<Interval<T>>::new();
+        for interval in self.iter() {
+            let mut rest = Some(*interval);
+            for cut in other.iter() {
+                let current = match rest {
+                    Some(current) => current,
+                    None => break,
+                };
+                if End::At(cut.start) >= current.end {
+                    break;
+                }
+                let (left, right) = current.difference(cut);
+                if let Some(left) = left {
+                    intervals.push(left);
+                }
+                rest = right;
+            }
+            if let Some(rest) = rest {
+                intervals.push(rest);
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: Unsigned> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> IntervalSet<T> {
+        IntervalSet::from_iter([interval])
+    }
+}
+
+impl<T: Unsigned> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<'a, T: Unsigned> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Unsigned> fmt::Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (n, interval) in self.intervals.iter().enumerate() {
+            if n > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", interval)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    // keeps integer literals from defaulting to i32
+    type Interval = super::Interval<usize>;
+
+    fn set(ranges: &[(usize, usize)]) -> IntervalSet {
+        ranges.iter().map(|(s, e)| Interval::new(*s, *e)).collect()
+    }
+
+    #[test]
+    fn interval_test() {
+        let interval = Interval::new(10, 15);
+        assert_eq!(interval.len(), 5);
+        assert!(interval.contains(10) && interval.contains(14));
+        assert!(!interval.contains(15) && !interval.contains(9));
+        assert!(Interval::new(3, 3).is_empty());
+        assert_eq!(Interval::from_len(10, 5), interval);
+        assert_eq!(interval.to_string(), "10..15");
+    }
+
+    #[test]
+    #[should_panic(expected = "interval 5..4 ends before it starts")]
+    fn backwards_interval_test() {
+        Interval::new(5, 4);
+    }
+
+    #[test]
+    fn interval_intersection_test() {
+        let interval = Interval::new(10, 20);
+        assert_eq!(interval.intersection(&Interval::new(0, 10)), None);
+        assert_eq!(
+            interval.intersection(&Interval::new(0, 11)),
+            Some(Interval::new(10, 11))
+        );
+        assert_eq!(
+            interval.intersection(&Interval::new(12, 14)),
+            Some(Interval::new(12, 14))
+        );
+        assert_eq!(
+            interval.intersection(&Interval::new(19, 40)),
+            Some(Interval::new(19, 20))
+        );
+        assert_eq!(interval.intersection(&Interval::new(20, 40)), None);
+        assert!(!interval.overlaps(&Interval::new(15, 15)));
+    }
+
+    #[test]
+    fn interval_difference_test() {
+        let interval = Interval::new(10, 20);
+        let some = |s, e| Some(Interval::new(s, e));
+        assert_eq!(
+            interval.difference(&Interval::new(0, 5)),
+            (None, some(10, 20))
+        );
+        assert_eq!(
+            interval.difference(&Interval::new(25, 30)),
+            (some(10, 20), None)
+        );
+        assert_eq!(
+            interval.difference(&Interval::new(5, 12)),
+            (None, some(12, 20))
+        );
+        assert_eq!(
+            interval.difference(&Interval::new(18, 30)),
+            (some(10, 18), None)
+        );
+        assert_eq!(
+            interval.difference(&Interval::new(12, 15)),
+            (some(10, 12), some(15, 20))
+        );
+        assert_eq!(interval.difference(&Interval::new(0, 30)), (None, None));
+    }
+
+    #[test]
+    fn interval_shift_test() {
+        assert_eq!(
+            Interval::new(12, 15).shift(10, 100),
+            Interval::new(102, 105)
+        );
+        assert_eq!(
+            Interval::new(102, 105).shift(100, 10),
+            Interval::new(12, 15)
+        );
+    }
+
+    #[test]
+    fn checked_test() {
+        assert_eq!(
+            super::Interval::try_from_len(u32::MAX - 2, 2),
+            Ok(super::Interval::new(u32::MAX - 2, u32::MAX))
+        );
+        assert_eq!(
+            super::Interval::try_from_len(u32::MAX - 2, 4)
+                .unwrap_err()
+                .to_string(),
+            "4294967293 + 4 overflows u32"
+        );
+
+        let top = super::Interval::new(u128::MAX - 10, u128::MAX);
+        assert_eq!(top.len(), 10);
+        assert_eq!(
+            top.try_shift(u128::MAX - 20, 0),
+            Ok(super::Interval::new(10, 20))
+        );
+        assert!(top.try_shift(0, 2).is_err());
+        assert!(top.try_shift(u128::MAX, 0).is_err());
+
+        let set: IntervalSet<u8> = IntervalSet::from(super::Interval::new(250, 255));
+        assert_eq!(
+            (set.min(), set.max(), set.count()),
+            (Some(250), Some(254), 5)
+        );
+    }
+
+    #[test]
+    fn up_to_max_test() {
+        let top = super::Interval::<u8>::try_from_len(250, 6).unwrap();
+        assert_eq!(top, super::Interval::inclusive(250, 255));
+        assert_eq!(top.end, End::PastMax);
+        assert_eq!((top.len(), top.last()), (6, 255));
+        assert!(top.contains(255) && !top.contains(249));
+        assert_eq!(top.to_string(), "250..=255");
+        assert_eq!(super::Interval::<u8>::inclusive(3, 4).to_string(), "3..5");
+
+        let json = serde_json::to_string(&[top, super::Interval::new(3, 5)]).unwrap();
+        assert_eq!(json, r#"[{"start":250,"end":null},{"start":3,"end":5}]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<super::Interval<u8>>>(&json).unwrap(),
+            vec![top, super::Interval::new(3, 5)]
+        );
+
+        assert_eq!(
+            top.intersection(&super::Interval::new(200, 252)),
+            Some(super::Interval::new(250, 252))
+        );
+        assert_eq!(
+            top.difference(&super::Interval::new(252, 253)),
+            (
+                Some(super::Interval::new(250, 252)),
+                Some(super::Interval::inclusive(253, 255))
+            )
+        );
+        assert_eq!(
+            top.difference(&super::Interval::inclusive(252, 255)),
+            (Some(super::Interval::new(250, 252)), None)
+        );
+        assert_eq!(top.shift(250, 0), super::Interval::new(0, 6));
+        assert_eq!(super::Interval::new(0, 6).shift(0, 250), top);
+        assert!(top.try_shift(250, 251).is_err());
+
+        let all = super::Interval::<u8>::inclusive(0, 255);
+        assert_eq!(all.shift(0, 0), all);
+        assert_eq!(
+            IntervalSet::from_iter([super::Interval::new(0, 250), top]).intervals(),
+            &[all]
+        );
+        let set = IntervalSet::from_iter([super::Interval::new(10, 20), top]);
+        assert!(set.contains(255) && !set.contains(20));
+        assert_eq!((set.max(), set.count()), (Some(255), 16));
+        assert_eq!(
+            IntervalSet::from(all).difference(&set),
+            IntervalSet::from_iter([super::Interval::new(0, 10), super::Interval::new(20, 250)])
+        );
+        assert_eq!(set.intersection(&IntervalSet::from(all)), set);
+        assert_eq!(set.to_string(), "{10..20, 250..=255}");
+    }
+
+    #[test]
+    #[should_panic(expected = "interval 0..=255 has more than u8::MAX values")]
+    fn len_overflow_test() {
+        super::Interval::<u8>::inclusive(0, 255).len();
+    }
+
+    #[test]
+    #[should_panic(expected = "250 + 7 overflows u8")]
+    fn from_len_overflow_test() {
+        super::Interval::<u8>::from_len(250, 7);
+    }
+
+    #[test]
+    fn insert_coalesces_test() {
+        let mut intervals = set(&[(10, 20), (30, 40)]);
+        assert_eq!(intervals.intervals().len(), 2);
+
+        intervals.insert(Interval::new(20, 25));
+        assert_eq!(intervals, set(&[(10, 25), (30, 40)]));
+        intervals.insert(Interval::new(5, 8));
+        intervals.insert(Interval::new(8, 8));
+        assert_eq!(intervals, set(&[(5, 8), (10, 25), (30, 40)]));
+        intervals.insert(Interval::new(7, 35));
+        assert_eq!(intervals.intervals(), &[Interval::new(5, 40)]);
+
+        assert_eq!(set(&[(3, 4), (1, 2), (2, 3)]).to_string(), "{1..4}");
+        assert_eq!(set(&[]).to_string(), "{}");
+    }
+
+    #[test]
+    fn set_queries_test() {
+        let intervals = set(&[(10, 20), (30, 40)]);
+        assert_eq!(intervals.count(), 20);
+        assert_eq!((intervals.min(), intervals.max()), (Some(10), Some(39)));
+        assert!(intervals.contains(10) && intervals.contains(39));
+        assert!(!intervals.contains(20) && !intervals.contains(29));
+        assert!(!intervals.contains(0) && !intervals.contains(40));
+        assert_eq!(IntervalSet::<usize>::new().min(), None);
+    }
+
+    #[test]
+    fn set_operations_test() {
+        let a = set(&[(0, 10), (20, 30), (40, 50)]);
+        let b = set(&[(5, 25), (45, 60)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 30), (40, 60)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (45, 50)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30), (40, 45)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (50, 60)]));
+
+        assert_eq!(a.difference(&a), IntervalSet::new());
+        assert_eq!(a.intersection(&IntervalSet::new()), IntervalSet::new());
+        assert_eq!(
+            set(&[(0, 100)]).difference(&set(&[(10, 20), (30, 40)])),
+            set(&[(0, 10), (20, 30), (40, 100)])
+        );
+    }
+}
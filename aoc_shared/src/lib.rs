@@ -1,5 +1,37 @@
 use std::fs;
 
+mod answers;
+mod bench;
+mod error;
+#[cfg(test)]
+mod fixtures;
+mod fuzz;
+mod grid;
+mod history;
+mod interval;
+mod num;
+mod parse;
+mod solution;
+mod testing;
+
+pub use answers::{
+    check_answers, load_answers, parse_answers, Answer, AnswerReport, Checked, Expected, Outcome,
+    PENDING,
+};
+pub use bench::{bench, format_duration, measure, BenchReport, Stats};
+pub use error::AocError;
+pub use fuzz::{shrink, Rng};
+pub use grid::{Grid, Pos};
+pub use history::{
+    append_history, find_regressions, input_hash, load_history, parse_history, HistoryEntry,
+    Regression,
+};
+pub use interval::{End, Interval, IntervalSet};
+pub use num::{Overflow, Unsigned};
+pub use parse::{column_of, next_token, parse_at, ParseError};
+pub use solution::{solve, Solution};
+pub use testing::run_aoc_test;
+
 #[macro_export]
 macro_rules! resource_path {
     ($res_path:expr) => {{
@@ -12,20 +44,48 @@ macro_rules! resource_path {
     }};
 }
 
+#[macro_export]
+macro_rules! try_resource_path {
+    ($res_path:expr) => {{
+        use std::path::Path;
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("resources")
+            .join($res_path);
+        match path.to_str() {
+            Some(it) => Ok(it.to_string()),
+            None => Err($crate::AocError::NonUtf8 {
+                path: path.to_string_lossy().to_string(),
+                valid_up_to: None,
+            }),
+        }
+    }};
+}
+
 pub fn read_file(file_path: &str) -> Result<String, String> {
-    let contents = fs::read_to_string(&file_path);
-    return match contents {
-        Ok(c) => Ok(c),
-        Err(r) => Err(format!("path: {} - {}", file_path, r.to_string())),
-    };
+    try_read_file(file_path).map_err(|e| e.to_string())
+}
+
+pub fn try_read_file(file_path: &str) -> Result<String, AocError> {
+    let bytes = fs::read(file_path).map_err(|e| AocError::io(file_path, e))?;
+    String::from_utf8(bytes).map_err(|e| AocError::NonUtf8 {
+        path: file_path.to_string(),
+        valid_up_to: Some(e.utf8_error().valid_up_to()),
+    })
 }
 
 pub fn get_resource_lines(res_path: &str) -> Vec<String> {
-    let mut lines: Vec<String> = read_file(&res_path)
-        .unwrap()
-        .split("\n")
-        .map(|s| s.to_string())
-        .collect();
+    match try_get_resource_lines(res_path) {
+        Ok(lines) => lines,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+pub fn try_get_resource_lines(res_path: &str) -> Result<Vec<String>, AocError> {
+    Ok(split_lines(&try_read_file(res_path)?))
+}
+
+pub fn split_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = content.split("\n").map(|s| s.to_string()).collect();
 
     // remove last line if empty
     if let Some(line) = lines.last() {
@@ -34,7 +94,7 @@ pub fn get_resource_lines(res_path: &str) -> Vec<String> {
         }
     }
 
-    return lines;
+    lines
 }
 
 #[cfg(test)]
@@ -65,4 +125,42 @@ mod tests {
         let got_content = read_file(&path).unwrap();
         assert_eq!("hello resource!\n", got_content);
     }
+
+    #[test]
+    fn test_try_resource_path() {
+        let path = try_resource_path!("sample.txt").unwrap();
+        assert_eq!(Some(path), resource_path!("sample.txt"));
+    }
+
+    #[test]
+    fn test_try_read_missing_file() {
+        let path = resource_path!("missing.txt").unwrap();
+        let err = try_read_file(&path).unwrap_err();
+        assert!(matches!(err, AocError::MissingFile { .. }));
+        assert_eq!(err.path(), Some(path.as_str()));
+        assert_eq!(read_file(&path), Err(format!("{}: file not found", path)));
+    }
+
+    #[test]
+    fn test_try_read_non_utf8_file() {
+        let path = resource_path!("non_utf8.txt").unwrap();
+        let err = try_read_file(&path).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("{}: invalid UTF-8 at byte 6", path)
+        );
+    }
+
+    #[test]
+    fn test_try_get_resource_lines() {
+        let path = resource_path!("sample.txt").unwrap();
+        assert_eq!(
+            try_get_resource_lines(&path).unwrap(),
+            vec!["hello resource!"]
+        );
+
+        let path = resource_path!("empty.txt").unwrap();
+        assert_eq!(try_get_resource_lines(&path).unwrap(), Vec::<String>::new());
+        assert_eq!(get_resource_lines(&path), Vec::<String>::new());
+    }
 }
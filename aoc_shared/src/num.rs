@@ -0,0 +1,107 @@
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display},
+    hash::Hash,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+/// The unsigned integer types puzzle values can be stored in.
+pub trait Unsigned:
+    Copy
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + Display
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Send
+    + Sync
+    + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+    const NAME: &'static str;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    fn try_add(self, other: Self) -> Result<Self, Overflow> {
+        self.checked_add(other)
+            .ok_or_else(|| Overflow::new::<Self>(format!("{} + {}", self, other)))
+    }
+
+    fn try_sub(self, other: Self) -> Result<Self, Overflow> {
+        self.checked_sub(other)
+            .ok_or_else(|| Overflow::new::<Self>(format!("{} - {}", self, other)))
+    }
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl Unsigned for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MAX: Self = <$t>::MAX;
+            const NAME: &'static str = stringify!($t);
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// An arithmetic operation whose result doesn't fit in its type.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Overflow {
+    pub expression: String,
+    pub type_name: &'static str,
+}
+
+impl Overflow {
+    pub fn new<T: Unsigned>(expression: String) -> Overflow {
+        Overflow {
+            expression,
+            type_name: T::NAME,
+        }
+    }
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} overflows {}", self.expression, self.type_name)
+    }
+}
+
+impl Error for Overflow {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn checked_test() {
+        assert_eq!(Unsigned::try_add(250u8, 5), Ok(255));
+        assert_eq!(
+            Unsigned::try_add(250u8, 6).unwrap_err().to_string(),
+            "250 + 6 overflows u8"
+        );
+        assert_eq!(
+            Unsigned::try_sub(3u128, 4).unwrap_err().to_string(),
+            "3 - 4 overflows u128"
+        );
+        assert_eq!(<u32 as Unsigned>::MAX, u32::MAX);
+        assert_eq!(<usize as Unsigned>::NAME, "usize");
+    }
+}
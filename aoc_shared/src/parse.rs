@@ -0,0 +1,157 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::AocError;
+
+/// A malformed token in a puzzle input. `line` and `column` are 1-based,
+/// `column` counts chars rather than bytes.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: &str) -> ParseError {
+        ParseError {
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    /// Error pointing at `token`, which must be a subslice of `text`.
+    pub fn at(line: usize, text: &str, token: &str, expected: &str) -> ParseError {
+        let found = if token.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("\"{}\"", token)
+        };
+        ParseError::new(line, column_of(text, token), expected, &found)
+    }
+
+    /// Error pointing just past the last char of `text`.
+    pub fn end_of_line(line: usize, text: &str, expected: &str) -> ParseError {
+        ParseError::new(line, text.chars().count() + 1, expected, "end of line")
+    }
+
+    pub fn with_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for AocError {
+    fn from(e: ParseError) -> AocError {
+        AocError::Parse {
+            path: None,
+            line: e.line,
+            column: e.column,
+            message: format!("expected {}, found {}", e.expected, e.found),
+        }
+    }
+}
+
+/// 1-based char column at which `part` starts inside `text`. `part` has to be
+/// a subslice of `text` (as returned by `split`, `trim`, ...), anything else
+/// is reported as column 1.
+pub fn column_of(text: &str, part: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).wrapping_sub(start);
+    if offset > text.len() || !text.is_char_boundary(offset) {
+        return 1;
+    }
+    text[..offset].chars().count() + 1
+}
+
+/// Parses `token` (a subslice of `text`) as a `T`.
+pub fn parse_at<T: FromStr>(
+    line: usize,
+    text: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at(line, text, token, expected))
+}
+
+/// Pulls the next token off `iter`, failing at the end of `text` if there is none.
+pub fn next_token<'a>(
+    iter: &mut impl Iterator<Item = &'a str>,
+    line: usize,
+    text: &str,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    iter.next()
+        .ok_or_else(|| ParseError::end_of_line(line, text, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn column_of_test() {
+        let text = "Game 15: 3 red";
+        let mut split = text.split_whitespace();
+        assert_eq!(column_of(text, split.next().unwrap()), 1);
+        assert_eq!(column_of(text, split.next().unwrap()), 6);
+        assert_eq!(column_of(text, split.next().unwrap()), 10);
+        assert_eq!(column_of(text, &text[text.len()..]), 15);
+        let not_a_slice = String::from("red");
+        assert_eq!(column_of(text, &not_a_slice), 1);
+
+        let text = "été 42";
+        assert_eq!(column_of(text, text.split(' ').nth(1).unwrap()), 5);
+    }
+
+    #[test]
+    fn parse_at_test() {
+        let text = "Time: 7 x5 30";
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        assert_eq!(parse_at::<i64>(2, text, tokens[1], "a number"), Ok(7));
+        let err = parse_at::<i64>(2, text, tokens[2], "a number").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 9, "a number", "\"x5\""));
+        assert_eq!(err.to_string(), "2:9: expected a number, found \"x5\"");
+    }
+
+    #[test]
+    fn next_token_test() {
+        let text = "3 red";
+        let mut split = text.split_whitespace();
+        assert_eq!(next_token(&mut split, 1, text, "a count"), Ok("3"));
+        assert_eq!(next_token(&mut split, 1, text, "a color"), Ok("red"));
+        assert_eq!(
+            next_token(&mut split, 1, text, "a color"),
+            Err(ParseError::new(1, 6, "a color", "end of line"))
+        );
+    }
+
+    #[test]
+    fn into_aoc_error_test() {
+        let parse_error = ParseError::new(4, 2, "a digit", "\"x\"");
+        let err: AocError = parse_error.clone().into();
+        assert_eq!(err.to_string(), "4:2: expected a digit, found \"x\"");
+        assert_eq!(err.to_string(), parse_error.to_string());
+        assert_eq!(
+            err.with_path("input.txt").to_string(),
+            "input.txt:4:2: expected a digit, found \"x\""
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+use crate::AocError;
+
+/// A day's puzzle split into its three stages so that generic tooling can
+/// drive any day the same way: parse the raw input once, then solve both
+/// parts from the parsed value.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// Parses `input` and solves the requested `part`, rendering the answer.
+pub fn solve<S: Solution>(input: &str, part: u32) -> Result<String, AocError> {
+    if part != 1 && part != 2 {
+        return Err(AocError::UnknownPart { part });
+    }
+    let parsed = S::parse(input)?;
+    match part {
+        1 => Ok(S::part1(&parsed).to_string()),
+        _ => Ok(S::part2(&parsed).to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SumLines;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn solve_test() {
+        assert_eq!(solve::<SumLines>("1\n2\n3\n", 1).unwrap(), "6");
+        assert_eq!(solve::<SumLines>("1\n2\n3\n", 2).unwrap(), "3");
+        assert_eq!(
+            solve::<SumLines>("1\n2\n", 3).unwrap_err().to_string(),
+            "no part 3"
+        );
+        assert_eq!(
+            solve::<SumLines>("1\nx\n", 1).unwrap_err().to_string(),
+            "2:1: expected a number, found \"x\""
+        );
+    }
+}
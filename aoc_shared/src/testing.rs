@@ -0,0 +1,102 @@
+use std::{fmt::Debug, time::Instant};
+
+use crate::{try_get_resource_lines, AocError};
+
+/// Body of the tests generated by `aoc_test!`: loads the input at `path`,
+/// runs `solve` on its lines and compares the result with `expected`.
+pub fn run_aoc_test<T, F>(name: &str, path: Result<String, AocError>, solve: F, expected: T)
+where
+    T: PartialEq + Debug,
+    F: FnOnce(&Vec<String>) -> T,
+{
+    let path = match path {
+        Ok(path) => path,
+        Err(e) => panic!("{}: {}", name, e),
+    };
+    let lines = match try_get_resource_lines(&path) {
+        Ok(lines) => lines,
+        Err(e) => panic!("{}: {}", name, e),
+    };
+
+    let start = Instant::now();
+    let got = solve(&lines);
+    let elapsed = start.elapsed();
+
+    if got != expected {
+        panic!(
+            "{} failed\n   input: {}\n    time: {:?}\nexpected: {:?}\n     got: {:?}",
+            name, path, elapsed, expected, got
+        );
+    }
+}
+
+/// Generates a test running a solver over a resource file:
+///
+/// ```ignore
+/// aoc_test!(test_sample_data, "test.txt", |lines| get_numbers(&as_strs(lines), Strategy::NumbersOnly), vec![12, 38, 15, 77]);
+/// aoc_test!(#[ignore = "private input"] test_real_data, "main.txt", |lines| sum_numbers(lines), 54076);
+/// ```
+///
+/// Attributes (`#[should_panic]`, ...) placed before the name are forwarded.
+/// Puzzle inputs that can't be committed go behind an `#[ignore]`; run those
+/// tests with `cargo test -- --ignored` where the file is present, without it
+/// they fail like any other missing input.
+#[macro_export]
+macro_rules! aoc_test {
+    ($(#[$meta:meta])* $name:ident, $input:expr, $solve:expr, $expected:expr) => {
+        #[test]
+        $(#[$meta])*
+        fn $name() {
+            $crate::run_aoc_test(
+                stringify!($name),
+                $crate::try_resource_path!($input),
+                $solve,
+                $expected,
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_test!(
+        lines_test,
+        "sample.txt",
+        |lines| lines.clone(),
+        vec!["hello resource!".to_string()]
+    );
+
+    aoc_test!(
+        #[ignore = "private input"]
+        ignored_input_test,
+        "sample.txt",
+        |lines| lines[0].len(),
+        15
+    );
+
+    // fails like any missing input once asked for with --ignored
+    aoc_test!(
+        #[ignore = "private input"]
+        #[should_panic(expected = "missing_private_input_test: ")]
+        missing_private_input_test,
+        "not_committed.txt",
+        |_| 0,
+        0
+    );
+
+    aoc_test!(
+        #[should_panic(expected = "missing_input_test: ")]
+        missing_input_test,
+        "not_committed.txt",
+        |_| 0,
+        0
+    );
+
+    aoc_test!(
+        #[should_panic(expected = "wrong_answer_test failed\n   input: ")]
+        wrong_answer_test,
+        "sample.txt",
+        |lines| lines.len(),
+        2
+    );
+}
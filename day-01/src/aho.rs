@@ -0,0 +1,202 @@
+use std::{collections::HashMap, str::CharIndices};
+
+/// An occurrence of a pattern, `start..end` are byte offsets into the text.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Match {
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Aho–Corasick automaton: finds every occurrence of a set of patterns,
+/// overlapping ones included, in a single pass over the text.
+pub struct AhoCorasick {
+    patterns: Vec<String>,
+    // trie edges, state 0 is the root
+    goto: Vec<HashMap<char, usize>>,
+    // longest proper suffix of a state that is also a state
+    fail: Vec<usize>,
+    // patterns ending at a state, through fail links too, longest first
+    outputs: Vec<Vec<usize>>,
+}
+
+impl AhoCorasick {
+    /// Empty patterns never match.
+    pub fn new(patterns: &[&str]) -> AhoCorasick {
+        let mut goto = vec![HashMap::<char, usize>::new()];
+        let mut outputs = vec![Vec::<usize>::new()];
+        for (index, pattern) in patterns.iter().enumerate() {
+            if pattern.is_empty() {
+                continue;
+            }
+            let mut state = 0;
+            for c in pattern.chars() {
+                state = match goto[state].get(&c) {
+                    Some(next) => *next,
+                    None => {
+                        let next = goto.len();
+                        goto.push(HashMap::new());
+                        outputs.push(vec![]);
+                        goto[state].insert(c, next);
+                        next
+                    }
+                };
+            }
+            outputs[state].push(index);
+        }
+
+        // breadth first, so the fail state of every parent is known
+        let mut fail = vec![0; goto.len()];
+        let mut queue: Vec<usize> = goto[0].values().cloned().collect();
+        let mut head = 0;
+        while head < queue.len() {
+            let state = queue[head];
+            head += 1;
+            let edges: Vec<(char, usize)> = goto[state].iter().map(|(c, s)| (*c, *s)).collect();
+            for (c, next) in edges {
+                let mut f = fail[state];
+                while f != 0 && !goto[f].contains_key(&c) {
+                    f = fail[f];
+                }
+                fail[next] = match goto[f].get(&c) {
+                    Some(s) if *s != next => *s,
+                    _ => 0,
+                };
+                let inherited = outputs[fail[next]].clone();
+                outputs[next].extend(inherited);
+                queue.push(next);
+            }
+        }
+
+        AhoCorasick {
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            goto,
+            fail,
+            outputs,
+        }
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    fn next_state(&self, mut state: usize, c: char) -> usize {
+        loop {
+            if let Some(next) = self.goto[state].get(&c) {
+                return *next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.fail[state];
+        }
+    }
+
+    /// Every match in `text`, ordered by where they end; matches ending at
+    /// the same place come longest first.
+    pub fn find_overlapping<'a>(&'a self, text: &'a str) -> Matches<'a> {
+        Matches {
+            automaton: self,
+            chars: text.char_indices(),
+            state: 0,
+            end: 0,
+            pending: &[],
+        }
+    }
+}
+
+pub struct Matches<'a> {
+    automaton: &'a AhoCorasick,
+    chars: CharIndices<'a>,
+    state: usize,
+    end: usize,
+    // matches ending at `end` that weren't returned yet
+    pending: &'a [usize],
+}
+
+impl Iterator for Matches<'_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        while self.pending.is_empty() {
+            let (index, c) = self.chars.next()?;
+            self.state = self.automaton.next_state(self.state, c);
+            self.end = index + c.len_utf8();
+            self.pending = &self.automaton.outputs[self.state];
+        }
+        let pattern = self.pending[0];
+        self.pending = &self.pending[1..];
+        Some(Match {
+            pattern,
+            start: self.end - self.automaton.patterns[pattern].len(),
+            end: self.end,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn found(automaton: &AhoCorasick, text: &str) -> Vec<(String, usize)> {
+        automaton
+            .find_overlapping(text)
+            .map(|m| (text[m.start..m.end].to_string(), m.start))
+            .collect()
+    }
+
+    #[test]
+    fn overlapping_test() {
+        let automaton = AhoCorasick::new(&["one", "two", "eight", "nine", "1"]);
+        assert_eq!(
+            found(&automaton, "xtwone1eightwo"),
+            vec![
+                ("two".to_string(), 1),
+                ("one".to_string(), 3),
+                ("1".to_string(), 6),
+                ("eight".to_string(), 7),
+                ("two".to_string(), 11),
+            ]
+        );
+        assert_eq!(found(&automaton, "nininine"), vec![("nine".to_string(), 4)]);
+        assert_eq!(found(&automaton, ""), vec![]);
+        assert_eq!(found(&automaton, "abc"), vec![]);
+    }
+
+    #[test]
+    fn nested_patterns_test() {
+        let automaton = AhoCorasick::new(&["he", "she", "his", "hers", ""]);
+        assert_eq!(
+            found(&automaton, "ushers"),
+            vec![
+                ("she".to_string(), 1),
+                ("he".to_string(), 2),
+                ("hers".to_string(), 2),
+            ]
+        );
+        let first = automaton.find_overlapping("ushers").next().unwrap();
+        assert_eq!(
+            first,
+            Match {
+                pattern: 1,
+                start: 1,
+                end: 4
+            }
+        );
+    }
+
+    #[test]
+    fn non_ascii_test() {
+        let automaton = AhoCorasick::new(&["zwölf", "eins", "öl"]);
+        assert_eq!(
+            found(&automaton, "äzwölfeins"),
+            vec![
+                ("öl".to_string(), 4),
+                ("zwölf".to_string(), 2),
+                ("eins".to_string(), 8),
+            ]
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt;
+
+use crate::{AhoCorasick, MatchOptions, Vocabulary};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Source {
+    Digit,
+    Word,
+}
+
+/// A digit or vocabulary word found in a line, `start..end` are byte offsets
+/// into the line as it was given, before any folding.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub source: Source,
+    pub value: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Calibration {
+    /// `tokens` are in the order they complete, `first` and `last` are the
+    /// first and last of them (the same token if there is only one).
+    Found {
+        tokens: Vec<Token>,
+        first: Token,
+        last: Token,
+    },
+    NoDigits,
+}
+
+impl Calibration {
+    pub fn value(&self) -> Option<i32> {
+        match self {
+            Calibration::Found { first, last, .. } => Some(first.value * 10 + last.value),
+            Calibration::NoDigits => None,
+        }
+    }
+
+    pub fn tokens(&self) -> &[Token] {
+        match self {
+            Calibration::Found { tokens, .. } => tokens,
+            Calibration::NoDigits => &[],
+        }
+    }
+}
+
+impl fmt::Display for Calibration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Calibration::Found { first, last, .. } => write!(
+                f,
+                "{} (first at {}..{}, last at {}..{})",
+                first.value * 10 + last.value,
+                first.start,
+                first.end,
+                last.start,
+                last.end
+            ),
+            Calibration::NoDigits => write!(f, "no digits"),
+        }
+    }
+}
+
+/// Finds the tokens of lines for one vocabulary with a single automaton,
+/// this is `Strategy::AhoCorasick`.
+pub struct Calibrator {
+    automaton: AhoCorasick,
+    // value and source of every pattern of the automaton
+    tokens: Vec<(i32, Source)>,
+    options: MatchOptions,
+}
+
+impl Calibrator {
+    pub fn new(vocabulary: &Vocabulary) -> Calibrator {
+        let words = vocabulary.folded_words();
+        let digits: Vec<String> = vocabulary.digits().map(|d| d.to_string()).collect();
+        let patterns: Vec<&str> = words
+            .iter()
+            .map(|(word, _)| word.as_str())
+            .chain(digits.iter().map(|d| d.as_str()))
+            .collect();
+        let tokens = words
+            .iter()
+            .map(|(_, value)| (*value, Source::Word))
+            .chain(
+                vocabulary
+                    .digits()
+                    .map(|d| (d as i32 - 0x30, Source::Digit)),
+            )
+            .collect();
+
+        Calibrator {
+            automaton: AhoCorasick::new(&patterns),
+            tokens,
+            options: vocabulary.options(),
+        }
+    }
+
+    pub fn calibrate_line(&self, line: &str) -> Calibration {
+        let (folded, spans) = self.options.fold_spans(line);
+        let tokens: Vec<Token> = self
+            .automaton
+            .find_overlapping(&folded)
+            .map(|m| {
+                let (value, source) = self.tokens[m.pattern];
+                Token {
+                    start: spans[m.start].0,
+                    end: spans[m.end - 1].1,
+                    source,
+                    value,
+                }
+            })
+            .collect();
+
+        return match (tokens.first(), tokens.last()) {
+            (Some(first), Some(last)) => Calibration::Found {
+                first: *first,
+                last: *last,
+                tokens,
+            },
+            _ => Calibration::NoDigits,
+        };
+    }
+
+    /// `calibrate_line(line).value()` without collecting the tokens.
+    pub fn value(&self, line: &str) -> Option<i32> {
+        let folded = self.options.fold(line);
+        let mut matches = self.automaton.find_overlapping(&folded);
+        let first = self.tokens[matches.next()?.pattern].0;
+        let last = match matches.last() {
+            Some(m) => self.tokens[m.pattern].0,
+            None => first,
+        };
+        return Some(first * 10 + last);
+    }
+}
+
+pub fn calibrate_line(line: &str, vocabulary: &Vocabulary) -> Calibration {
+    Calibrator::new(vocabulary).calibrate_line(line)
+}
+
+/// One calibration per line, `NoDigits` included, so indexes match `lines`.
+pub fn calibrate_lines(lines: &Vec<&str>, vocabulary: &Vocabulary) -> Vec<Calibration> {
+    let calibrator = Calibrator::new(vocabulary);
+    lines
+        .iter()
+        .map(|line| calibrator.calibrate_line(line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::{read_file, resource_path};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{get_numbers, Strategy};
+
+    fn token(start: usize, end: usize, source: Source, value: i32) -> Token {
+        Token {
+            start,
+            end,
+            source,
+            value,
+        }
+    }
+
+    #[test]
+    fn calibrate_line_test() {
+        let calibration = calibrate_line("xtwone3four", &Vocabulary::english());
+        let tokens = vec![
+            token(1, 4, Source::Word, 2),
+            token(3, 6, Source::Word, 1),
+            token(6, 7, Source::Digit, 3),
+            token(7, 11, Source::Word, 4),
+        ];
+        assert_eq!(
+            calibration,
+            Calibration::Found {
+                tokens: tokens.clone(),
+                first: tokens[0],
+                last: tokens[3],
+            }
+        );
+        assert_eq!(calibration.value(), Some(24));
+        assert_eq!(calibration.to_string(), "24 (first at 1..4, last at 7..11)");
+
+        let single = calibrate_line("ab7c", &Vocabulary::english());
+        assert_eq!(single.value(), Some(77));
+        assert_eq!(single.tokens(), &[token(2, 3, Source::Digit, 7)]);
+    }
+
+    #[test]
+    fn no_digits_test() {
+        let lines = vec!["two1nine", "", "abc", "Seven"];
+        let calibrations = calibrate_lines(&lines, &Vocabulary::english());
+        let values: Vec<Option<i32>> = calibrations.iter().map(|c| c.value()).collect();
+        assert_eq!(values, vec![Some(29), None, None, None]);
+        assert_eq!(calibrations[1], Calibration::NoDigits);
+        assert_eq!(calibrations[2].to_string(), "no digits");
+        assert_eq!(calibrations[1].tokens(), &[]);
+    }
+
+    #[test]
+    fn folded_spans_test() {
+        let vocabulary =
+            Vocabulary::new(vec![("fünf".to_string(), 5)]).with_options(MatchOptions {
+                ignore_case: true,
+                ignore_accents: true,
+            });
+        let line = "1FU\u{308}NFx";
+        let calibration = calibrate_line(line, &vocabulary);
+        assert_eq!(
+            calibration.tokens(),
+            &[token(0, 1, Source::Digit, 1), token(1, 7, Source::Word, 5)]
+        );
+        assert_eq!(&line[1..7], "FU\u{308}NF");
+    }
+
+    #[test]
+    fn matches_get_numbers_test() {
+        let path = resource_path!("input2.txt").unwrap();
+        let content = read_file(&path).unwrap();
+        let lines = content.split("\n").collect();
+        let calibrator = Calibrator::new(&Vocabulary::english());
+        let values: Vec<i32> = calibrate_lines(&lines, &Vocabulary::english())
+            .iter()
+            .filter_map(|c| c.value())
+            .collect();
+        assert_eq!(values, get_numbers(&lines, Strategy::WrapReplace));
+        for line in lines {
+            assert_eq!(
+                calibrator.value(line),
+                calibrator.calibrate_line(line).value()
+            );
+        }
+    }
+}
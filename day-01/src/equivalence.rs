@@ -0,0 +1,168 @@
+//! Randomized checks that every `Strategy` finds the same calibration values
+//! as an oracle trying each word at each position of the line.
+
+use aoc_shared::{shrink, Rng};
+use pretty_assertions::assert_eq;
+
+use crate::{NumberFinder, Strategy, Vocabulary, DIGIT_WORDS};
+
+/// Words sharing letters, the cases `fix_line` and the matchers get wrong
+/// most easily.
+const OVERLAPPING: [&str; 10] = [
+    "twone",
+    "eighthree",
+    "oneight",
+    "sevenine",
+    "eightwo",
+    "nineight",
+    "threeight",
+    "fiveight",
+    "nininine",
+    "oneighthreeightwo",
+];
+/// Almost words, that a matcher could take for a word or lose track in.
+const FRAGMENTS: [&str; 8] = ["tw", "eigh", "nin", "on", "thre", "sev", "fiv", "ei"];
+const LETTERS: &str = "eightwonsvrfuxz";
+
+/// The calibration value of `line` for a strategy, `None` if it has none.
+type Numbers<'a> = &'a mut dyn FnMut(Strategy, &str) -> Option<i32>;
+
+fn uses_words(strategy: Strategy) -> bool {
+    !matches!(strategy, Strategy::NumbersOnly | Strategy::ByteScan)
+}
+
+fn oracle(strategy: Strategy, line: &str) -> Option<i32> {
+    let words: &[&str] = match uses_words(strategy) {
+        true => &DIGIT_WORDS,
+        false => &[],
+    };
+    let values: Vec<i32> = line
+        .char_indices()
+        .filter_map(|(i, c)| match c.to_digit(10) {
+            // '0' isn't a digit with the english vocabulary
+            Some(digit) if digit > 0 => Some(digit as i32),
+            Some(_) => None,
+            None => words
+                .iter()
+                .position(|word| line[i..].starts_with(word))
+                .map(|index| index as i32 + 1),
+        })
+        .collect();
+    return Some(values.first()? * 10 + values.last()?);
+}
+
+fn random_line(rng: &mut Rng) -> String {
+    let letters: Vec<char> = LETTERS.chars().collect();
+    let mut line = String::new();
+    for _ in 0..rng.range(0..8) {
+        match rng.range(0..5) {
+            0 => line.push_str(rng.choose::<&str>(&DIGIT_WORDS)),
+            1 => line.push_str(rng.choose::<&str>(&OVERLAPPING)),
+            2 => line.push_str(rng.choose::<&str>(&FRAGMENTS)),
+            3 => line.push(*rng.choose(&letters)),
+            _ => line.push((b'0' + rng.range(0..10) as u8) as char),
+        }
+    }
+    return line;
+}
+
+/// Lines with a run of chars, or a single char, taken out.
+fn smaller(line: &String) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut lines = Vec::<String>::new();
+    let mut len = chars.len();
+    while len > 0 {
+        for start in (0..=chars.len() - len).step_by(len) {
+            let mut shorter = chars.clone();
+            shorter.drain(start..start + len);
+            lines.push(shorter.into_iter().collect());
+        }
+        len /= 2;
+    }
+    return lines;
+}
+
+/// The first strategy not agreeing with the oracle on `line`.
+fn differing(line: &str, numbers: Numbers) -> Option<Strategy> {
+    Strategy::ALL
+        .into_iter()
+        .find(|strategy| numbers(*strategy, line) != oracle(*strategy, line))
+}
+
+/// Checks `cases` random lines, returning the shrunk first failure.
+fn run(seed: u64, cases: usize, numbers: Numbers) -> Result<(), (String, String)> {
+    let mut rng = Rng::new(seed);
+    for n in 0..cases {
+        let line = random_line(&mut rng);
+        let strategy = match differing(&line, numbers) {
+            Some(strategy) => strategy,
+            None => continue,
+        };
+
+        let minimal = shrink(line, smaller, |l| {
+            numbers(strategy, l) != oracle(strategy, l)
+        });
+        let mut message = format!(
+            "{:?} disagrees on {:?} (seed {}, case {}), expected {:?}",
+            strategy,
+            minimal,
+            seed,
+            n,
+            oracle(strategy, &minimal)
+        );
+        for other in Strategy::ALL {
+            message += &format!("\n  {:?}: {:?}", other, numbers(other, &minimal));
+        }
+        return Err((minimal, message));
+    }
+    return Ok(());
+}
+
+fn finders() -> Vec<NumberFinder> {
+    Strategy::ALL
+        .iter()
+        .map(|strategy| NumberFinder::new(*strategy, &Vocabulary::english()))
+        .collect()
+}
+
+fn find(finders: &mut Vec<NumberFinder>, strategy: Strategy, line: &str) -> Option<i32> {
+    let index = Strategy::ALL.iter().position(|s| *s == strategy).unwrap();
+    return finders[index].find(line);
+}
+
+#[test]
+fn oracle_test() {
+    assert_eq!(oracle(Strategy::AhoCorasick, "xtwone3four"), Some(24));
+    assert_eq!(oracle(Strategy::AhoCorasick, "eighthree"), Some(83));
+    assert_eq!(oracle(Strategy::ByteScan, "eighthree"), None);
+    assert_eq!(oracle(Strategy::NumbersOnly, "a1b2c3"), Some(13));
+    assert_eq!(oracle(Strategy::StateMachines, "0"), None);
+    assert_eq!(oracle(Strategy::ByteScan, "a0b7"), Some(77));
+}
+
+#[test]
+fn equivalence_test() {
+    let mut finders = finders();
+    let mut numbers = |strategy: Strategy, line: &str| find(&mut finders, strategy, line);
+    for seed in 0..4 {
+        if let Err((_, message)) = run(seed, 2000, &mut numbers) {
+            panic!("{}", message);
+        }
+    }
+}
+
+#[test]
+fn shrinks_counterexample_test() {
+    // state machines losing "eight" when it shares its "t" with "two"
+    let mut finders = finders();
+    let mut numbers = |strategy: Strategy, line: &str| match strategy {
+        Strategy::StateMachines => find(&mut finders, strategy, &line.replace("eightwo", "ightwo")),
+        _ => find(&mut finders, strategy, line),
+    };
+
+    let (minimal, message) = run(0, 2000, &mut numbers).unwrap_err();
+    assert_eq!(minimal, "eightwo");
+    assert!(message.starts_with("StateMachines disagrees on \"eightwo\""));
+    assert!(message.contains("expected Some(82)"));
+    assert!(message.contains("\n  StateMachines: Some(22)\n  WrapReplace: Some(82)"));
+}
@@ -0,0 +1,140 @@
+// lowercase latin letters with diacritics and the letter they are based on
+const ACCENTED: &str =
+    "àáâãäåçèéêëìíîïñòóôõöøùúûüýÿāăąćĉċčďđēĕėęěĝğġģĥħĩīĭįĵķĺļľŀłńņňōŏőŕŗřśŝşšţťŧũūŭůűųŵŷźżž";
+const PLAIN: &str =
+    "aaaaaaceeeeiiiinoooooouuuuyyaaaccccddeeeeegggghhiiiijklllllnnnooorrrsssstttuuuuuuwyzzz";
+
+/// How loosely vocabulary words are matched. Both are off by default, so
+/// chars have to be identical.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct MatchOptions {
+    pub ignore_case: bool,
+    /// Latin letters match with or without diacritics, combining marks are
+    /// dropped.
+    pub ignore_accents: bool,
+}
+
+fn is_combining_mark(c: char) -> bool {
+    ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+fn strip_accent(c: char) -> char {
+    if c.is_ascii() {
+        return c;
+    }
+    let lower = match c.to_lowercase().next() {
+        Some(lower) => lower,
+        None => return c,
+    };
+    return match ACCENTED.chars().position(|a| a == lower) {
+        Some(index) if c == lower => PLAIN.chars().nth(index).unwrap(),
+        Some(index) => PLAIN.chars().nth(index).unwrap().to_ascii_uppercase(),
+        None => c,
+    };
+}
+
+impl MatchOptions {
+    pub fn is_exact(&self) -> bool {
+        !self.ignore_case && !self.ignore_accents
+    }
+
+    /// Pushes the chars `c` is compared as onto `out`: usually one, none for
+    /// an ignored combining mark, more for some lowercase mappings.
+    pub fn fold_char(&self, c: char, out: &mut Vec<char>) {
+        if self.ignore_case {
+            for lower in c.to_lowercase() {
+                self.push_folded(lower, out);
+            }
+        } else {
+            self.push_folded(c, out);
+        }
+    }
+
+    fn push_folded(&self, c: char, out: &mut Vec<char>) {
+        if !self.ignore_accents {
+            out.push(c);
+        } else if !is_combining_mark(c) {
+            out.push(strip_accent(c));
+        }
+    }
+
+    pub fn fold(&self, text: &str) -> String {
+        if self.is_exact() {
+            return text.to_string();
+        }
+        let mut chars = Vec::<char>::with_capacity(text.len());
+        for c in text.chars() {
+            self.fold_char(c, &mut chars);
+        }
+        return chars.into_iter().collect();
+    }
+
+    /// `fold`, and for every byte of the folded text the byte range of the
+    /// char of `text` it comes from.
+    pub fn fold_spans(&self, text: &str) -> (String, Vec<(usize, usize)>) {
+        let mut folded = String::with_capacity(text.len());
+        let mut spans = Vec::<(usize, usize)>::with_capacity(text.len());
+        let mut chars = Vec::<char>::new();
+        for (start, c) in text.char_indices() {
+            let end = start + c.len_utf8();
+            chars.clear();
+            self.fold_char(c, &mut chars);
+            for folded_c in &chars {
+                folded.push(*folded_c);
+                spans.extend((0..folded_c.len_utf8()).map(|_| (start, end)));
+            }
+        }
+        return (folded, spans);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const CASE: MatchOptions = MatchOptions {
+        ignore_case: true,
+        ignore_accents: false,
+    };
+    const ACCENTS: MatchOptions = MatchOptions {
+        ignore_case: false,
+        ignore_accents: true,
+    };
+    const BOTH: MatchOptions = MatchOptions {
+        ignore_case: true,
+        ignore_accents: true,
+    };
+
+    #[test]
+    fn tables_test() {
+        assert_eq!(ACCENTED.chars().count(), PLAIN.chars().count());
+    }
+
+    #[test]
+    fn fold_test() {
+        let text = "Fünf ÉLF Ǆ three";
+        assert_eq!(MatchOptions::default().fold(text), text);
+        assert_eq!(CASE.fold(text), "fünf élf ǆ three");
+        assert_eq!(ACCENTS.fold(text), "Funf ELF Ǆ three");
+        assert_eq!(BOTH.fold(text), "funf elf ǆ three");
+
+        // decomposed accents and multi char lowercase mappings
+        assert_eq!(ACCENTS.fold("cafe\u{301}"), "cafe");
+        assert_eq!(CASE.fold("İ"), "i\u{307}");
+        assert_eq!(BOTH.fold("İ"), "i");
+        assert_eq!(BOTH.fold("ŁÓDŹ ÿŸ"), "lodz yy");
+    }
+
+    #[test]
+    fn fold_spans_test() {
+        let (folded, spans) = BOTH.fold_spans("aÉe\u{301}İ");
+        assert_eq!(folded, "aeei");
+        assert_eq!(spans, vec![(0, 1), (1, 3), (3, 4), (6, 8)]);
+
+        let (folded, spans) = MatchOptions::default().fold_spans("ü1");
+        assert_eq!(folded, "ü1");
+        assert_eq!(spans, vec![(0, 2), (0, 2), (2, 3)]);
+    }
+}
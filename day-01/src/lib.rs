@@ -0,0 +1,716 @@
+// predates the workspace, kept in its original style
+#![allow(
+    clippy::clone_on_copy,
+    clippy::needless_return,
+    clippy::partialeq_to_none,
+    clippy::ptr_arg,
+    clippy::single_match
+)]
+
+mod aho;
+mod calibrate;
+#[cfg(test)]
+mod equivalence;
+mod fold;
+mod scan;
+mod stream;
+mod vocabulary;
+
+use std::ops::RangeInclusive;
+
+pub use aho::{AhoCorasick, Match, Matches};
+use aoc_shared::{measure, split_lines, AocError, Solution, Stats};
+pub use calibrate::{calibrate_line, calibrate_lines, Calibration, Calibrator, Source, Token};
+pub use fold::MatchOptions;
+pub use scan::{first_digit, last_digit, scan_value};
+pub use stream::{iter_numbers, read_numbers, Numbers, ReadNumbers};
+pub use vocabulary::Vocabulary;
+
+pub const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct WordMathcer {
+    word: Vec<char>,
+    // length of the longest proper prefix of word[..=i] that is also a
+    // suffix of it, where to resume after a mismatch
+    fallback: Vec<usize>,
+    index: usize,
+}
+
+impl WordMathcer {
+    pub fn new(word: &str) -> WordMathcer {
+        let word: Vec<char> = word.chars().collect();
+        let mut fallback = vec![0; word.len()];
+        let mut len = 0;
+        for i in 1..word.len() {
+            while len > 0 && word[i] != word[len] {
+                len = fallback[len - 1];
+            }
+            if word[i] == word[len] {
+                len += 1;
+            }
+            fallback[i] = len;
+        }
+
+        WordMathcer {
+            word,
+            fallback,
+            index: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.index = 0
+    }
+
+    // returns true if the char completed a word match
+    pub fn next_char(&mut self, c_in: char) -> bool {
+        if self.word.is_empty() {
+            return false;
+        }
+
+        while self.index > 0 && self.word[self.index] != c_in {
+            self.index = self.fallback[self.index - 1];
+        }
+        if self.word[self.index] == c_in {
+            self.index += 1;
+        }
+
+        if self.index == self.word.len() {
+            self.index = self.fallback[self.index - 1];
+            return true;
+        };
+        return false;
+    }
+}
+
+pub fn fix_line(line: &str) -> String {
+    return fix_line_with(line, &Vocabulary::english());
+}
+
+/// Puts the value of every word of `vocabulary` in the middle of it, so the
+/// letters stay around for the words overlapping it.
+pub fn fix_line_with(line: &str, vocabulary: &Vocabulary) -> String {
+    let mut line = vocabulary.options().fold(line);
+    for (word, value) in vocabulary.folded_words() {
+        while line.contains(word.as_str()) {
+            line = line.replacen(word.as_str(), format!("!!!{}!!!", value).as_str(), 1);
+        }
+        line = line.replace("!!!", &word);
+    }
+
+    return line;
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Strategy {
+    NumbersOnly,
+    StateMachines,
+    WrapReplace,
+    AhoCorasick,
+    /// Digits only, like `NumbersOnly`, scanning the bytes from both ends.
+    ByteScan,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 5] = [
+        Strategy::NumbersOnly,
+        Strategy::StateMachines,
+        Strategy::WrapReplace,
+        Strategy::AhoCorasick,
+        Strategy::ByteScan,
+    ];
+}
+
+pub fn get_numbers(lines: &Vec<&str>, strategy: Strategy) -> Vec<i32> {
+    return get_numbers_with(lines, strategy, &Vocabulary::english());
+}
+
+/// Finds the calibration value of lines one at a time, keeping what the
+/// strategy needs around between lines.
+pub struct NumberFinder {
+    strategy: Strategy,
+    vocabulary: Vocabulary,
+    digits: RangeInclusive<char>,
+    word_matchrs: Vec<(WordMathcer, i32)>,
+    calibrator: Calibrator,
+}
+
+impl NumberFinder {
+    pub fn new(strategy: Strategy, vocabulary: &Vocabulary) -> NumberFinder {
+        let word_matchrs = vocabulary
+            .folded_words()
+            .iter()
+            .map(|(word, value)| (WordMathcer::new(word), *value))
+            .collect();
+        NumberFinder {
+            strategy,
+            vocabulary: vocabulary.clone(),
+            digits: vocabulary.digits(),
+            word_matchrs,
+            calibrator: Calibrator::new(vocabulary),
+        }
+    }
+
+    fn has_zero(&self) -> bool {
+        *self.digits.start() == '0'
+    }
+
+    /// The calibration value of `line`, `None` if it has no digits.
+    pub fn find(&mut self, line: &str) -> Option<i32> {
+        let strategy = self.strategy;
+        match strategy {
+            Strategy::AhoCorasick => return self.calibrator.value(line),
+            Strategy::ByteScan => return scan_value(line.as_bytes(), self.has_zero()),
+            _ => {}
+        }
+
+        let line = match strategy {
+            Strategy::NumbersOnly => line.to_string(),
+            // words are replaced by their value, literal '0's that aren't
+            // digits are hidden so that a '0' left is a word worth 0
+            Strategy::WrapReplace if !self.has_zero() => {
+                fix_line_with(&line.replace('0', " "), &self.vocabulary)
+            }
+            Strategy::WrapReplace => fix_line_with(line, &self.vocabulary),
+            _ => self.vocabulary.options().fold(line),
+        };
+        let digits = match strategy {
+            Strategy::WrapReplace => '0'..='9',
+            _ => self.digits.clone(),
+        };
+
+        for (matcher, _) in self.word_matchrs.iter_mut() {
+            matcher.reset();
+        }
+
+        let mut first: Option<i32> = None;
+        let mut second: Option<i32> = None;
+
+        for c in line.chars() {
+            let mut num: Option<i32> = None;
+
+            if digits.contains(&c) {
+                num = Some(c as i32 - 0x30);
+            }
+
+            if strategy == Strategy::StateMachines {
+                for (matcher, value) in self.word_matchrs.iter_mut() {
+                    if matcher.next_char(c) {
+                        num = Some(*value);
+                    }
+                }
+            }
+
+            match num {
+                Some(n) => {
+                    if first == None {
+                        first = Some(n);
+                    } else {
+                        second = Some(n);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        return match first {
+            Some(f) => match second {
+                Some(s) => Some((f * 10) + s),
+                None => Some((f * 10) + f),
+            },
+            None => None,
+        };
+    }
+}
+
+pub fn get_numbers_with(
+    lines: &Vec<&str>,
+    strategy: Strategy,
+    vocabulary: &Vocabulary,
+) -> Vec<i32> {
+    let mut finder = NumberFinder::new(strategy, vocabulary);
+    return lines.iter().filter_map(|line| finder.find(line)).collect();
+}
+
+/// Timings of `get_numbers` over `lines` for every strategy.
+pub fn bench_strategies(lines: &Vec<&str>, iterations: usize) -> Vec<(Strategy, Stats)> {
+    Strategy::ALL
+        .iter()
+        .map(|strategy| {
+            let stats = measure(iterations, || get_numbers(lines, *strategy));
+            (*strategy, stats)
+        })
+        .collect()
+}
+
+pub fn main_input() -> Option<String> {
+    aoc_shared::resource_path!("input.txt")
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(split_lines(input))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let lines: Vec<&str> = input.iter().map(|l| l.as_str()).collect();
+        get_numbers(&lines, Strategy::ByteScan).iter().sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let lines: Vec<&str> = input.iter().map(|l| l.as_str()).collect();
+        get_numbers(&lines, Strategy::StateMachines).iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::io::BufReader;
+
+    use aoc_shared::aoc_test;
+    use aoc_shared::format_duration;
+    use aoc_shared::measure;
+    use aoc_shared::read_file;
+    use aoc_shared::resource_path;
+    use aoc_shared::Rng;
+    use pretty_assertions::assert_eq;
+
+    use crate::bench_strategies;
+    use crate::Strategy;
+    use crate::get_numbers;
+    use crate::get_numbers_with;
+    use crate::read_numbers;
+    use crate::MatchOptions;
+    use crate::Vocabulary;
+    use crate::WordMathcer;
+    use crate::Day1;
+
+    aoc_shared::answers_test!(Day1);
+
+    fn as_strs(lines: &Vec<String>) -> Vec<&str> {
+        lines.iter().map(|l| l.as_str()).collect()
+    }
+
+    #[test]
+    fn test_word_matcher() {
+        let mut wm = WordMathcer::new("hello");
+        assert!(!wm.next_char('h'));
+        assert!(!wm.next_char('e'));
+        assert!(!wm.next_char('l'));
+        assert!(!wm.next_char('l'));
+        assert!(wm.next_char('o'));
+
+        assert!(!wm.next_char('h'));
+        assert!(!wm.next_char('x'));
+        assert!(!wm.next_char('e'));
+        assert!(!wm.next_char('l'));
+        assert!(!wm.next_char('l'));
+        assert!(!wm.next_char('o'));
+    }
+
+    #[test]
+    fn test_matcher_one() {
+        let mut wm = WordMathcer::new("one");
+        assert!(!wm.next_char('o'));
+        assert!(!wm.next_char('n'));
+        assert!(wm.next_char('e'));
+
+        let numbers_got = get_numbers(&vec!["1nineeight"], Strategy::StateMachines);
+        assert_eq!(vec![18], numbers_got);
+    }
+
+    #[test]
+    fn test_matcher_line() {
+        let numbers_got = get_numbers(
+            &vec![
+                "two1nine",
+                "eightwothree",
+                "abcone2threexyz",
+                "xtwone3four",
+                "4nineeightseven2",
+                "zoneight234",
+                "7pqrstsixteen",
+                "eighthree",
+                "sevenine",
+                "oneight",
+                "xtwone3four",
+                "three7one7",
+                "eightwothree",
+                "oooneeone",
+                "eight7eight",
+            ],
+            Strategy::StateMachines,
+        );
+        assert_eq!(
+            vec![29, 83, 13, 24, 42, 14, 76, 83, 79, 18, 24, 37, 83, 11, 88],
+            numbers_got
+        );
+    }
+
+    #[test]
+    fn test_get_numbers() {
+        let lines_in = vec!["12", "1a2", "a12", "a1a2a", "132", "a1a3a2a"];
+        let numbers_got = get_numbers(&lines_in, Strategy::StateMachines);
+        assert_eq!(vec![12; 6], numbers_got);
+
+        let lines_in = vec![
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        let numbers_got = get_numbers(&lines_in, Strategy::StateMachines);
+        assert_eq!(vec![11, 22, 33, 44, 55, 66, 77, 88, 99], numbers_got);
+    }
+
+    aoc_test!(
+        test_sample_data,
+        "test.txt",
+        |lines| get_numbers(&as_strs(lines), Strategy::NumbersOnly),
+        vec![12, 38, 15, 77]
+    );
+
+    aoc_test!(
+        test_sample_data_sum,
+        "test.txt",
+        |lines| get_numbers(&as_strs(lines), Strategy::NumbersOnly)
+            .iter()
+            .sum::<i32>(),
+        142
+    );
+
+    aoc_test!(
+        test_sample_data2,
+        "test2.txt",
+        |lines| get_numbers(&as_strs(lines), Strategy::StateMachines),
+        vec![29, 83, 13, 24, 42, 14, 76]
+    );
+
+    aoc_test!(
+        test_sample_data2_sum,
+        "test2.txt",
+        |lines| get_numbers(&as_strs(lines), Strategy::StateMachines)
+            .iter()
+            .sum::<i32>(),
+        281
+    );
+
+    aoc_test!(
+        test_real_data2_wrap_replace,
+        "input2.txt",
+        |lines| get_numbers(&as_strs(lines), Strategy::WrapReplace)
+            .iter()
+            .sum::<i32>(),
+        54076
+    );
+
+    #[test]
+    fn test_extended_vocabulary() {
+        let path = resource_path!("vocabulary/extended.txt").unwrap();
+        let vocabulary = Vocabulary::load(&path).unwrap();
+        let lines_in = vec!["zero", "zero5", "4zero", "twozero", "zerone", "a0b7", "xyz"];
+        let with_zero = vocabulary.clone().with_zero_digit(true);
+        for strategy in [
+            Strategy::StateMachines,
+            Strategy::WrapReplace,
+            Strategy::AhoCorasick,
+        ] {
+            assert_eq!(
+                get_numbers_with(&lines_in, strategy, &vocabulary),
+                vec![0, 5, 40, 20, 1, 77],
+                "{:?}",
+                strategy
+            );
+            assert_eq!(
+                get_numbers_with(&lines_in, strategy, &with_zero),
+                vec![0, 5, 40, 20, 1, 7],
+                "{:?}",
+                strategy
+            );
+        }
+        assert_eq!(
+            get_numbers_with(&lines_in, Strategy::NumbersOnly, &vocabulary),
+            vec![55, 44, 77]
+        );
+
+        // '0' is only a digit when asked for, with or without words
+        for strategy in Strategy::ALL {
+            assert_eq!(
+                get_numbers(&vec!["a0b7", "0", "zero"], strategy),
+                vec![77],
+                "{:?}",
+                strategy
+            );
+            assert_eq!(
+                get_numbers_with(
+                    &vec!["a0b7", "0", "zero"],
+                    strategy,
+                    &Vocabulary::english().with_zero_digit(true)
+                ),
+                vec![7, 0],
+                "{:?}",
+                strategy
+            );
+        }
+    }
+
+    #[test]
+    fn test_spanish_vocabulary() {
+        let path = resource_path!("vocabulary/spanish.txt").unwrap();
+        let vocabulary = Vocabulary::load(&path).unwrap();
+        let lines_in = vec![
+            "dos3cuatro",
+            "unocho",
+            "ceroseis",
+            "tresiete",
+            "one2three",
+            "xnuevex",
+        ];
+        for strategy in [
+            Strategy::StateMachines,
+            Strategy::WrapReplace,
+            Strategy::AhoCorasick,
+        ] {
+            assert_eq!(
+                get_numbers_with(&lines_in, strategy, &vocabulary),
+                vec![24, 18, 6, 37, 22, 99],
+                "{:?}",
+                strategy
+            );
+        }
+    }
+
+    #[test]
+    fn test_word_matcher_unicode() {
+        let mut wm = WordMathcer::new("fünf");
+        assert!(!wm.next_char('f'));
+        assert!(!wm.next_char('ü'));
+        assert!(!wm.next_char('n'));
+        assert!(wm.next_char('f'));
+        // the last f starts the next match
+        assert!(!wm.next_char('ü'));
+        assert!(!wm.next_char('n'));
+        assert!(wm.next_char('f'));
+
+        // a mismatch resumes from the longest partial match
+        let mut wm = WordMathcer::new("nine");
+        let got: Vec<bool> = "ninine".chars().map(|c| wm.next_char(c)).collect();
+        assert_eq!(got, vec![false, false, false, false, false, true]);
+
+        let mut wm = WordMathcer::new("");
+        assert!(!wm.next_char('a'));
+    }
+
+    const WORD_STRATEGIES: [Strategy; 3] = [
+        Strategy::StateMachines,
+        Strategy::WrapReplace,
+        Strategy::AhoCorasick,
+    ];
+
+    #[test]
+    fn test_german_vocabulary() {
+        let path = resource_path!("vocabulary/german.txt").unwrap();
+        let german = Vocabulary::load(&path).unwrap();
+        let lines_in = vec![
+            "xfünf3", "achtzehn", "dreinull", "zweins", "FÜNF", "funf", "Fünf",
+        ];
+        let options = |ignore_case, ignore_accents| MatchOptions {
+            ignore_case,
+            ignore_accents,
+        };
+
+        for strategy in WORD_STRATEGIES {
+            let numbers = |options: MatchOptions| {
+                let vocabulary = german.clone().with_options(options);
+                get_numbers_with(&lines_in, strategy, &vocabulary)
+            };
+            let name = format!("{:?}", strategy);
+            assert_eq!(
+                numbers(options(false, false)),
+                vec![53, 88, 30, 21],
+                "{}",
+                name
+            );
+            assert_eq!(
+                numbers(options(true, false)),
+                vec![53, 88, 30, 21, 55, 55],
+                "{}",
+                name
+            );
+            assert_eq!(
+                numbers(options(false, true)),
+                vec![53, 88, 30, 21, 55],
+                "{}",
+                name
+            );
+            assert_eq!(
+                numbers(options(true, true)),
+                vec![53, 88, 30, 21, 55, 55, 55],
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_mixed_case_lines() {
+        let lines_in = vec!["Two1NINE", "EightWoThree", "abcONE2threexyz"];
+        let ignore_case = Vocabulary::english().with_options(MatchOptions {
+            ignore_case: true,
+            ignore_accents: false,
+        });
+        let cyrillic = Vocabulary::new(vec![
+            ("один".to_string(), 1),
+            ("два".to_string(), 2),
+            ("три".to_string(), 3),
+        ])
+        .with_options(ignore_case.options());
+
+        for strategy in WORD_STRATEGIES {
+            assert_eq!(
+                get_numbers_with(&lines_in, strategy, &Vocabulary::english()),
+                vec![11, 23],
+                "{:?}",
+                strategy
+            );
+            assert_eq!(
+                get_numbers_with(&lines_in, strategy, &ignore_case),
+                vec![29, 83, 13],
+                "{:?}",
+                strategy
+            );
+            assert_eq!(
+                get_numbers_with(&vec!["ДВАодин", "тРи"], strategy, &cyrillic),
+                vec![21, 33],
+                "{:?}",
+                strategy
+            );
+        }
+    }
+
+    #[test]
+    fn test_aho_corasick_lines() {
+        let lines_in = vec![
+            "eightwothree",
+            "xtwone3four",
+            "zoneight234",
+            "7pqrstsixteen",
+            "nininine",
+            "oneighthree",
+            "abc",
+            "5",
+        ];
+        let numbers_got = get_numbers(&lines_in, Strategy::AhoCorasick);
+        assert_eq!(vec![83, 24, 14, 76, 99, 13, 55], numbers_got);
+    }
+
+    aoc_test!(
+        test_sample_data2_aho_corasick,
+        "test2.txt",
+        |lines| get_numbers(&as_strs(lines), Strategy::AhoCorasick),
+        vec![29, 83, 13, 24, 42, 14, 76]
+    );
+
+    #[test]
+    fn check_aho_corasick() {
+        let path = resource_path!("input2.txt").unwrap();
+        let content = read_file(&path).unwrap();
+        let lines = content.split("\n").collect();
+        assert_eq!(
+            get_numbers(&lines, Strategy::WrapReplace),
+            get_numbers(&lines, Strategy::AhoCorasick)
+        );
+    }
+
+    #[test]
+    fn test_bench_strategies() {
+        let lines = vec!["two1nine", "xtwone3four"];
+        let timings = bench_strategies(&lines, 2);
+        let strategies: Vec<Strategy> = timings.iter().map(|(s, _)| *s).collect();
+        assert_eq!(strategies, Strategy::ALL.to_vec());
+    }
+
+    // cargo test -p day-1 --release -- --ignored --nocapture
+    #[test]
+    #[ignore]
+    fn bench_strategies_on_input() {
+        let path = resource_path!("input2.txt").unwrap();
+        let content = read_file(&path).unwrap().repeat(20);
+        let lines = content.split("\n").collect();
+        for (strategy, stats) in bench_strategies(&lines, 20) {
+            println!(
+                "{:<14} median {:>9}  p95 {:>9}",
+                format!("{:?}", strategy),
+                format_duration(stats.median_ns),
+                format_duration(stats.p95_ns)
+            );
+        }
+    }
+
+    // cargo test -p day-1 --release -- --ignored --nocapture
+    #[test]
+    #[ignore]
+    fn bench_strategies_on_synthetic_input() {
+        // ~100MB of long lines with few digits, where scanning from both ends
+        // pays off the most. Timed from a file, as the runner reads it.
+        let mut rng = Rng::new(0);
+        let mut content = String::new();
+        for _ in 0..1_000_000 {
+            for _ in 0..rng.range(20..180) {
+                content.push(match rng.one_in(60) {
+                    true => (b'0' + rng.range(0..10) as u8) as char,
+                    false => (b'a' + rng.range(0..26) as u8) as char,
+                });
+            }
+            content.push('\n');
+        }
+        let path = std::env::temp_dir().join(format!("day_1_synthetic_{}.txt", std::process::id()));
+        fs::write(&path, &content).unwrap();
+        drop(content);
+
+        let read_sum = |strategy: Strategy| -> i64 {
+            let file = BufReader::new(File::open(&path).unwrap());
+            read_numbers(file, strategy, &Vocabulary::english())
+                .map(|n| n.unwrap() as i64)
+                .sum()
+        };
+        assert_eq!(
+            read_sum(Strategy::ByteScan),
+            read_sum(Strategy::NumbersOnly)
+        );
+        for strategy in Strategy::ALL {
+            let stats = measure(5, || read_sum(strategy));
+            println!(
+                "{:<14} median {:>9}  p95 {:>9}",
+                format!("{:?}", strategy),
+                format_duration(stats.median_ns),
+                format_duration(stats.p95_ns)
+            );
+        }
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn check_state_machines() {
+        let path = resource_path!("input2.txt").unwrap();
+        let content = read_file(&path).unwrap();
+        let lines = content.split("\n").collect();
+        let numbers_exp = get_numbers(&lines, Strategy::WrapReplace);
+        let numbers_got = get_numbers(&lines, Strategy::StateMachines);
+
+        for (index, exp) in numbers_exp.iter().enumerate() {
+            let exp = exp.clone();
+            let got = numbers_got[index];
+            if got != exp {
+                println!("{} - exp: {}, got: {}", lines[index], exp, got);
+            }
+            assert_eq!(exp, got);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! `Strategy::ByteScan`: ASCII digits only, found eight bytes at a time.
+
+const LANES: usize = 8;
+const ONES: u64 = u64::from_le_bytes([0x01; LANES]);
+const HIGH: u64 = ONES * 0x80;
+
+/// The high bit of every byte of `chunk` that is an ASCII digit, `'0'` only
+/// counting if `zero`.
+///
+/// `b ^ b'0'` is 0 to 9 for digits only. The high bit of a byte is set by
+/// `(x & 0x7f) + 0x76` when its low bits are past 9 and by `x` itself past
+/// 0x7f, and by `(x & 0x7f) + 0x7f` unless they are 0. The sums never carry
+/// into the next byte.
+fn digit_mask(chunk: u64, zero: bool) -> u64 {
+    let x = chunk ^ (ONES * b'0' as u64);
+    let low = x & !HIGH;
+    let digits = !((low + ONES * 0x76) | x) & HIGH;
+    if zero {
+        return digits;
+    }
+    return digits & (low + ONES * 0x7f);
+}
+
+fn is_digit(b: u8, zero: bool) -> bool {
+    b.is_ascii_digit() && (zero || b != b'0')
+}
+
+/// Index of the first ASCII digit of `bytes`, see `digit_mask` for `zero`.
+pub fn first_digit(bytes: &[u8], zero: bool) -> Option<usize> {
+    let mut chunks = bytes.chunks_exact(LANES);
+    for (index, chunk) in chunks.by_ref().enumerate() {
+        let mask = digit_mask(u64::from_le_bytes(chunk.try_into().unwrap()), zero);
+        if mask != 0 {
+            return Some(index * LANES + mask.trailing_zeros() as usize / 8);
+        }
+    }
+    let rest = chunks.remainder();
+    return rest
+        .iter()
+        .position(|b| is_digit(*b, zero))
+        .map(|i| bytes.len() - rest.len() + i);
+}
+
+/// Index of the last ASCII digit of `bytes`, see `digit_mask` for `zero`.
+pub fn last_digit(bytes: &[u8], zero: bool) -> Option<usize> {
+    let mut chunks = bytes.rchunks_exact(LANES);
+    for (index, chunk) in chunks.by_ref().enumerate() {
+        let mask = digit_mask(u64::from_le_bytes(chunk.try_into().unwrap()), zero);
+        if mask != 0 {
+            let start = bytes.len() - (index + 1) * LANES;
+            return Some(start + LANES - 1 - mask.leading_zeros() as usize / 8);
+        }
+    }
+    return chunks.remainder().iter().rposition(|b| is_digit(*b, zero));
+}
+
+/// The calibration value of `line` from its digits only, same as
+/// `Strategy::NumbersOnly` without decoding chars or copying the line. `'0'`
+/// is a digit only if `zero`, see `Vocabulary::with_zero_digit`.
+pub fn scan_value(line: &[u8], zero: bool) -> Option<i32> {
+    let first = first_digit(line, zero)?;
+    // there is one, the first one if nothing else
+    let last = last_digit(&line[first..], zero).unwrap() + first;
+    return Some((line[first] - b'0') as i32 * 10 + (line[last] - b'0') as i32);
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::Rng;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{get_numbers, get_numbers_with, Strategy, Vocabulary};
+
+    fn naive(bytes: &[u8], zero: bool) -> (Option<usize>, Option<usize>) {
+        (
+            bytes.iter().position(|b| is_digit(*b, zero)),
+            bytes.iter().rposition(|b| is_digit(*b, zero)),
+        )
+    }
+
+    #[test]
+    fn digit_mask_test() {
+        for zero in [false, true] {
+            for b in 0..=255u8 {
+                let mask = digit_mask(u64::from_le_bytes([b; LANES]), zero);
+                let expected = if is_digit(b, zero) { HIGH } else { 0 };
+                assert_eq!(mask, expected, "byte {:#x}, zero {}", b, zero);
+            }
+        }
+        let chunk = u64::from_le_bytes(*b"a1/:9\xb0\xb90");
+        assert_eq!(digit_mask(chunk, true), 0x8000_0080_0000_8000);
+        assert_eq!(digit_mask(chunk, false), 0x0000_0080_0000_8000);
+    }
+
+    #[test]
+    fn every_position_test() {
+        // one or two digits anywhere in lines shorter and longer than a chunk
+        for len in 0..3 * LANES {
+            for a in 0..len {
+                for b in a..len {
+                    let mut line = vec![b'x'; len];
+                    line[a] = b'3';
+                    line[b] = b'8';
+                    assert_eq!(first_digit(&line, false), Some(a), "{} {} {}", len, a, b);
+                    assert_eq!(last_digit(&line, false), Some(b), "{} {} {}", len, a, b);
+                }
+            }
+            assert_eq!(first_digit(&vec![b'0'; len], false), None);
+            assert_eq!(last_digit(&vec![b'0'; len], false), None);
+        }
+    }
+
+    #[test]
+    fn scan_value_test() {
+        assert_eq!(scan_value(b"pqr3stu8vwx", false), Some(38));
+        assert_eq!(scan_value(b"treb7uchet", false), Some(77));
+        assert_eq!(scan_value("zwölf 1 ٣ 2 fünf".as_bytes(), false), Some(12));
+        assert_eq!(scan_value(b"abc", false), None);
+        assert_eq!(scan_value(b"", false), None);
+
+        assert_eq!(scan_value(b"0abcdefghij0", true), Some(0));
+        assert_eq!(scan_value(b"0abcdefghij0", false), None);
+        assert_eq!(scan_value(b"a0bcdefghij7k", false), Some(77));
+        assert_eq!(scan_value(b"a0bcdefghij7k", true), Some(7));
+    }
+
+    #[test]
+    fn random_lines_test() {
+        // bytes near the digits, and multi byte chars, are the likely mistakes
+        let alphabet: Vec<char> = "0123456789/:a \u{b0}\u{b9}é٣€🎄".chars().collect();
+        let mut rng = Rng::new(0);
+        let mut lines = Vec::<String>::new();
+        for _ in 0..2000 {
+            let len = rng.range(0..40);
+            let line: String = (0..len)
+                .map(|_| match rng.one_in(4) {
+                    true => *rng.choose(&alphabet),
+                    false => 'x',
+                })
+                .collect();
+            for zero in [false, true] {
+                let (first, last) = naive(line.as_bytes(), zero);
+                assert_eq!(first_digit(line.as_bytes(), zero), first, "{:?}", line);
+                assert_eq!(last_digit(line.as_bytes(), zero), last, "{:?}", line);
+            }
+            lines.push(line);
+        }
+
+        let lines: Vec<&str> = lines.iter().map(|l| l.as_str()).collect();
+        assert_eq!(
+            get_numbers(&lines, Strategy::ByteScan),
+            get_numbers(&lines, Strategy::NumbersOnly)
+        );
+        let with_zero = Vocabulary::english().with_zero_digit(true);
+        assert_eq!(
+            get_numbers_with(&lines, Strategy::ByteScan, &with_zero),
+            get_numbers_with(&lines, Strategy::NumbersOnly, &with_zero)
+        );
+    }
+}
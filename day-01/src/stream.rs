@@ -0,0 +1,226 @@
+use std::io::{self, BufRead};
+
+use crate::{NumberFinder, Strategy, Vocabulary};
+
+/// `line` without its `\n` or `\r\n` ending.
+fn trim_line_ending(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    return line.strip_suffix('\r').unwrap_or(line);
+}
+
+/// Calibration values of the lines of a reader, read one line at a time into
+/// the same buffer, so memory only grows with the longest line.
+pub struct ReadNumbers<R> {
+    reader: R,
+    line: String,
+    finder: NumberFinder,
+}
+
+impl<R: BufRead> Iterator for ReadNumbers<R> {
+    type Item = io::Result<i32>;
+
+    fn next(&mut self) -> Option<io::Result<i32>> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    if let Some(n) = self.finder.find(trim_line_ending(&self.line)) {
+                        return Some(Ok(n));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Like `get_numbers_with`, lazily over the lines of `reader`. Lines may end
+/// in `\n` or `\r\n`, lines without digits are skipped.
+pub fn read_numbers<R: BufRead>(
+    reader: R,
+    strategy: Strategy,
+    vocabulary: &Vocabulary,
+) -> ReadNumbers<R> {
+    ReadNumbers {
+        reader,
+        line: String::new(),
+        finder: NumberFinder::new(strategy, vocabulary),
+    }
+}
+
+pub struct Numbers<I> {
+    lines: I,
+    finder: NumberFinder,
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Iterator for Numbers<I> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        loop {
+            let line = self.lines.next()?;
+            if let Some(n) = self.finder.find(trim_line_ending(line)) {
+                return Some(n);
+            }
+        }
+    }
+}
+
+/// Like `read_numbers`, for lines that are already in memory, such as
+/// `content.split('\n')` of a file with `\r\n` endings.
+pub fn iter_numbers<'a, I: Iterator<Item = &'a str>>(
+    lines: I,
+    strategy: Strategy,
+    vocabulary: &Vocabulary,
+) -> Numbers<I> {
+    Numbers {
+        lines,
+        finder: NumberFinder::new(strategy, vocabulary),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, Read};
+
+    use aoc_shared::{read_file, resource_path};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::get_numbers;
+
+    // the second sample, with a blank line and mixed line endings
+    const SAMPLE: &str = concat!(
+        "two1nine\r\n",
+        "eightwothree\n",
+        "abcone2threexyz\r\n",
+        "xtwone3four\n",
+        "4nineeightseven2\n",
+        "zoneight234\r\n",
+        "7pqrstsixteen\n",
+        "\r\n",
+    );
+    const SAMPLE_SUM: i64 = 281;
+
+    /// `SAMPLE` over and over, generated as it's read.
+    struct Repeated {
+        cycles: u64,
+        offset: usize,
+    }
+
+    impl Read for Repeated {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.cycles == 0 {
+                return Ok(0);
+            }
+            let rest = &SAMPLE.as_bytes()[self.offset..];
+            let n = rest.len().min(buf.len());
+            buf[..n].copy_from_slice(&rest[..n]);
+            self.offset += n;
+            if self.offset == SAMPLE.len() {
+                self.offset = 0;
+                self.cycles -= 1;
+            }
+            Ok(n)
+        }
+    }
+
+    fn sum_repeated(cycles: u64, strategy: Strategy) -> (i64, u64) {
+        let reader = BufReader::new(Repeated { cycles, offset: 0 });
+        let mut sum = 0;
+        let mut count = 0;
+        for n in read_numbers(reader, strategy, &Vocabulary::english()) {
+            sum += n.unwrap() as i64;
+            count += 1;
+        }
+        (sum, count)
+    }
+
+    #[test]
+    fn read_numbers_test() {
+        let numbers: Vec<i32> = read_numbers(
+            SAMPLE.as_bytes(),
+            Strategy::StateMachines,
+            &Vocabulary::english(),
+        )
+        .map(|n| n.unwrap())
+        .collect();
+        assert_eq!(numbers, vec![29, 83, 13, 24, 42, 14, 76]);
+
+        // no line ending on the last line
+        let numbers: Vec<i32> = read_numbers(
+            "1abc\r\nx9".as_bytes(),
+            Strategy::NumbersOnly,
+            &Vocabulary::english(),
+        )
+        .map(|n| n.unwrap())
+        .collect();
+        assert_eq!(numbers, vec![11, 99]);
+    }
+
+    #[test]
+    fn crlf_test() {
+        // what splitting a \r\n file on \n leaves
+        let lines = vec!["eight\r", "9five\r", "six\r\n"];
+        let numbers: Vec<i32> = iter_numbers(
+            lines.into_iter(),
+            Strategy::WrapReplace,
+            &Vocabulary::english(),
+        )
+        .collect();
+        assert_eq!(numbers, vec![88, 95, 66]);
+    }
+
+    #[test]
+    fn invalid_utf8_test() {
+        let mut numbers = read_numbers(
+            &b"1\n\xff2\n3\n"[..],
+            Strategy::NumbersOnly,
+            &Vocabulary::english(),
+        );
+        assert_eq!(numbers.next().unwrap().unwrap(), 11);
+        assert_eq!(
+            numbers.next().unwrap().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(numbers.next().unwrap().unwrap(), 33);
+        assert!(numbers.next().is_none());
+    }
+
+    #[test]
+    fn matches_get_numbers_test() {
+        let path = resource_path!("input2.txt").unwrap();
+        let content = read_file(&path).unwrap();
+        let lines: Vec<&str> = content.split("\n").collect();
+        for strategy in Strategy::ALL {
+            let file = BufReader::new(std::fs::File::open(&path).unwrap());
+            let streamed: Vec<i32> = read_numbers(file, strategy, &Vocabulary::english())
+                .map(|n| n.unwrap())
+                .collect();
+            assert_eq!(streamed, get_numbers(&lines, strategy), "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn generated_input_test() {
+        // ~2MB
+        let cycles = 20_000;
+        for strategy in [Strategy::StateMachines, Strategy::AhoCorasick] {
+            let (sum, count) = sum_repeated(cycles, strategy);
+            assert_eq!(sum, SAMPLE_SUM * cycles as i64, "{:?}", strategy);
+            assert_eq!(count, 7 * cycles);
+        }
+    }
+
+    // cargo test -p day-1 --release -- --ignored
+    #[test]
+    #[ignore]
+    fn multi_gigabyte_input_test() {
+        // ~4GB, never held in memory
+        let cycles = 4_000_000_000 / SAMPLE.len() as u64;
+        let (sum, count) = sum_repeated(cycles, Strategy::AhoCorasick);
+        assert_eq!(sum, SAMPLE_SUM * cycles as i64);
+        assert_eq!(count, 7 * cycles);
+    }
+}
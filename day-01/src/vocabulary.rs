@@ -0,0 +1,181 @@
+use std::ops::RangeInclusive;
+
+use aoc_shared::{next_token, parse_at, try_read_file, AocError, ParseError};
+
+use crate::{MatchOptions, DIGIT_WORDS};
+
+/// Spelled out digits recognised next to the digits themselves, in the order
+/// `fix_line` replaces them.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Vocabulary {
+    words: Vec<(String, i32)>,
+    options: MatchOptions,
+    // whether '0' is read as a digit, the puzzle has none
+    zero_digit: bool,
+}
+
+impl Vocabulary {
+    pub fn new(words: Vec<(String, i32)>) -> Vocabulary {
+        Vocabulary {
+            words,
+            options: MatchOptions::default(),
+            zero_digit: false,
+        }
+    }
+
+    pub fn with_options(mut self, options: MatchOptions) -> Vocabulary {
+        self.options = options;
+        self
+    }
+
+    pub fn with_zero_digit(mut self, zero_digit: bool) -> Vocabulary {
+        self.zero_digit = zero_digit;
+        self
+    }
+
+    /// "one" to "nine", what the puzzle uses.
+    pub fn english() -> Vocabulary {
+        let words = DIGIT_WORDS
+            .iter()
+            .zip(1..)
+            .map(|(word, value)| (word.to_string(), value))
+            .collect();
+        Vocabulary::new(words)
+    }
+
+    pub fn words(&self) -> &[(String, i32)] {
+        &self.words
+    }
+
+    pub fn options(&self) -> MatchOptions {
+        self.options
+    }
+
+    /// The chars read as digits: '1' to '9' like the puzzle, and '0' as well
+    /// if set with `with_zero_digit`.
+    pub fn digits(&self) -> RangeInclusive<char> {
+        match self.zero_digit {
+            true => '0'..='9',
+            false => '1'..='9',
+        }
+    }
+
+    /// The words as lines are matched against, see `MatchOptions::fold`.
+    pub fn folded_words(&self) -> Vec<(String, i32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (self.options.fold(word), *value))
+            .collect()
+    }
+
+    /// One `word value` pair per line, blank lines and lines starting with
+    /// `#` are skipped:
+    ///
+    /// ```text
+    /// # english, with zero
+    /// zero 0
+    /// one 1
+    /// ```
+    pub fn parse(content: &str) -> Result<Vocabulary, ParseError> {
+        let mut words = Vec::<(String, i32)>::new();
+        for (index, line) in content.lines().enumerate() {
+            let line_no = index + 1;
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let word = next_token(&mut tokens, line_no, line, "a word")?;
+            if words.iter().any(|(w, _)| w == word) {
+                return Err(ParseError::at(line_no, line, word, "a new word"));
+            }
+            let value_str = next_token(&mut tokens, line_no, line, "a value")?;
+            let value: i32 = parse_at(line_no, line, value_str, "a value from 0 to 9")?;
+            if !(0..=9).contains(&value) {
+                return Err(ParseError::at(
+                    line_no,
+                    line,
+                    value_str,
+                    "a value from 0 to 9",
+                ));
+            }
+            if let Some(extra) = tokens.next() {
+                return Err(ParseError::at(line_no, line, extra, "end of line"));
+            }
+            words.push((word.to_string(), value));
+        }
+        return Ok(Vocabulary::new(words));
+    }
+
+    pub fn load(path: &str) -> Result<Vocabulary, AocError> {
+        let content = try_read_file(path)?;
+        return Vocabulary::parse(&content).map_err(|e| AocError::from(e).with_path(path));
+    }
+}
+
+impl Default for Vocabulary {
+    fn default() -> Vocabulary {
+        Vocabulary::english()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::resource_path;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let vocabulary = Vocabulary::parse("# comment\n\nzero 0\n  one\t1\n").unwrap();
+        assert_eq!(
+            vocabulary.words(),
+            &[("zero".to_string(), 0), ("one".to_string(), 1)]
+        );
+        assert_eq!(Vocabulary::parse("").unwrap().words(), &[]);
+        // a word worth 0 doesn't make '0' a digit
+        assert_eq!(vocabulary.digits(), '1'..='9');
+        assert_eq!(vocabulary.with_zero_digit(true).digits(), '0'..='9');
+        assert_eq!(Vocabulary::english().digits(), '1'..='9');
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        let error = |content: &str| Vocabulary::parse(content).unwrap_err();
+        assert_eq!(
+            error("one 1\ntwo"),
+            ParseError::new(2, 4, "a value", "end of line")
+        );
+        assert_eq!(
+            error("one x"),
+            ParseError::new(1, 5, "a value from 0 to 9", "\"x\"")
+        );
+        assert_eq!(
+            error("ten 10"),
+            ParseError::new(1, 5, "a value from 0 to 9", "\"10\"")
+        );
+        assert_eq!(
+            error("one 1 2"),
+            ParseError::new(1, 7, "end of line", "\"2\"")
+        );
+        assert_eq!(
+            error("one 1\n one 2"),
+            ParseError::new(2, 2, "a new word", "\"one\"")
+        );
+    }
+
+    #[test]
+    fn load_test() {
+        let path = resource_path!("vocabulary/spanish.txt").unwrap();
+        let vocabulary = Vocabulary::load(&path).unwrap();
+        assert_eq!(vocabulary.words().len(), 10);
+        assert_eq!(vocabulary.words()[0], ("cero".to_string(), 0));
+
+        let path = resource_path!("vocabulary/bad.txt").unwrap();
+        assert_eq!(
+            Vocabulary::load(&path).unwrap_err().to_string(),
+            format!("{}:2:6: expected a value from 0 to 9, found \"11\"", path)
+        );
+    }
+}
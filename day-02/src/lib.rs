@@ -0,0 +1,249 @@
+// predates the workspace, kept in its original style
+#![allow(clippy::for_kv_map, clippy::needless_return)]
+
+use std::collections::HashMap;
+
+use aoc_shared::{next_token, parse_at, split_lines, AocError, ParseError, Solution};
+
+type Hand = HashMap<String, i32>;
+
+pub struct Game {
+    pub id: usize,
+    pub hands: Vec<Hand>,
+}
+
+// errors are reported on line 1, `parse_games` fixes up the line number
+pub fn parse_game(line: &str) -> Result<Game, ParseError> {
+    let mut colon_split = line.splitn(2, ':');
+    let game_title = colon_split.next().unwrap_or("");
+    let hand_strs = match colon_split.next() {
+        Some(h) => h.split(";"),
+        None => return Err(ParseError::end_of_line(1, line, "':'")),
+    };
+
+    let mut title_split = game_title.split_whitespace();
+    let keyword = next_token(&mut title_split, 1, line, "\"Game\"")?;
+    if keyword != "Game" {
+        return Err(ParseError::at(1, line, keyword, "\"Game\""));
+    }
+    let id_str = next_token(&mut title_split, 1, line, "a game id")?;
+
+    let mut game = Game {
+        id: parse_at(1, line, id_str, "a game id")?,
+        hands: Vec::new(),
+    };
+
+    for hand_str in hand_strs {
+        let mut hand = Hand::new();
+        for cube_str in hand_str.split(",") {
+            let trimmed_cube_str = cube_str.trim();
+            let mut cube_split = trimmed_cube_str.split_whitespace();
+            let count_str = cube_split
+                .next()
+                .ok_or_else(|| ParseError::at(1, line, trimmed_cube_str, "a cube count"))?;
+            let count = parse_at(1, line, count_str, "a cube count")?;
+            let color = cube_split
+                .next()
+                .ok_or_else(|| ParseError::at(1, line, &cube_str[cube_str.len()..], "a color"))?
+                .to_string();
+            if let Some(extra) = cube_split.next() {
+                return Err(ParseError::at(1, line, extra, "',' or ';'"));
+            }
+            hand.insert(color, count);
+        }
+        game.hands.push(hand);
+    }
+
+    return Ok(game);
+}
+
+pub fn parse_games(lines: Vec<String>) -> Result<Vec<Game>, ParseError> {
+    let mut games = Vec::<Game>::new();
+    for (index, line) in lines.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        games.push(parse_game(line).map_err(|e| e.with_line(index + 1))?);
+    }
+    return Ok(games);
+}
+
+pub fn sum_possible(max_counts: &Hand, games: &Vec<Game>) -> usize {
+    let mut possible_sum: usize = 0;
+    for game in games {
+        let mut is_possible = true;
+        for (key, value) in max_counts {
+            for hand in &game.hands {
+                if !hand.contains_key(key) {
+                    continue;
+                }
+                if hand[key] > *value {
+                    is_possible = false;
+                    break;
+                }
+            }
+            if !is_possible {
+                break;
+            }
+        }
+        if is_possible {
+            possible_sum += game.id;
+        }
+    }
+    return possible_sum;
+}
+pub fn sum_min_power(games: &Vec<Game>) -> i32 {
+    let mut min_power_sum = 0;
+    for game in games {
+        let mut min_cubes = Hand::new();
+        min_cubes.insert("red".to_string(), 0);
+        min_cubes.insert("green".to_string(), 0);
+        min_cubes.insert("blue".to_string(), 0);
+
+        for hand in &game.hands {
+            // colors other than red, green and blue don't make up the power
+            for (color, value) in hand {
+                if let Some(min) = min_cubes.get_mut(color) {
+                    if value > min {
+                        *min = *value;
+                    }
+                }
+            }
+        }
+        let mut power = 1;
+        for (_, value) in min_cubes {
+            power *= value;
+        }
+
+        min_power_sum += power;
+    }
+    return min_power_sum;
+}
+
+pub fn main_input() -> Option<String> {
+    aoc_shared::resource_path!("main1.txt")
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_games(split_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let max_counts: Hand = [("red", 12), ("green", 13), ("blue", 14)]
+            .iter()
+            .map(|(color, count)| (color.to_string(), *count))
+            .collect();
+        sum_possible(&max_counts, input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        sum_min_power(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    aoc_shared::answers_test!(Day2);
+
+    #[test]
+    fn game_parse_test() {
+        let game_str = "Game 15: 3 red, 1 green; 1 blue, 2 yellow";
+        let game = parse_game(game_str).unwrap();
+        assert_eq!(game.id, 15);
+
+        let game1_in = &game.hands[0];
+        let game1_exp = make_hand(&[("red", 3), ("green", 1)]);
+        match_hands(game1_in, &game1_exp);
+
+        let game2_in = &game.hands[1];
+        let game2_exp = make_hand(&[("blue", 1), ("yellow", 2)]);
+        match_hands(game2_in, &game2_exp);
+    }
+    #[test]
+    fn parse_errors_test() {
+        assert_eq!(
+            parse_game("Game 15 3 red").err(),
+            Some(ParseError::new(1, 14, "':'", "end of line"))
+        );
+        assert_eq!(
+            parse_game("Gaem 15: 3 red").err(),
+            Some(ParseError::new(1, 1, "\"Game\"", "\"Gaem\""))
+        );
+        assert_eq!(
+            parse_game("Game x: 3 red").err(),
+            Some(ParseError::new(1, 6, "a game id", "\"x\""))
+        );
+        assert_eq!(
+            parse_game("Game 1: 3 red, green").err(),
+            Some(ParseError::new(1, 16, "a cube count", "\"green\""))
+        );
+        assert_eq!(
+            parse_game("Game 1: 3 red; 4").err(),
+            Some(ParseError::new(1, 17, "a color", "nothing"))
+        );
+        assert_eq!(
+            parse_game("Game 1: 3 red 4 blue").err(),
+            Some(ParseError::new(1, 15, "',' or ';'", "\"4\""))
+        );
+        assert_eq!(
+            parse_game("Game 1: 3 red,").err(),
+            Some(ParseError::new(1, 15, "a cube count", "nothing"))
+        );
+
+        let lines = vec!["Game 1: 3 red".to_string(), "Game 2: red".to_string()];
+        assert_eq!(
+            parse_games(lines).err(),
+            Some(ParseError::new(2, 9, "a cube count", "\"red\""))
+        );
+    }
+
+    #[test]
+    fn other_colors_test() {
+        let input = "Game 1: 3 red, 2 yellow; 4 green, 5 blue\nGame 2: 1 purple\n";
+        let games = Day2::parse(input).unwrap();
+        assert_eq!(Day2::part1(&games), 3);
+        // game 2 has none of the three colors, so a power of 0
+        assert_eq!(Day2::part2(&games), 60);
+    }
+
+    fn make_hand(pairs: &[(&str, i32)]) -> Hand {
+        let mut hand = Hand::new();
+        for (key, value) in pairs {
+            hand.insert(key.to_string(), *value);
+        }
+        return hand;
+    }
+    fn assert_hand_value(hand: &Hand, key: &str, exp: i32) {
+        assert_eq!(
+            format!("hand[{}] = {}", key, exp),
+            format!("hand[{}] = {}", key, hand[key])
+        );
+    }
+
+    fn match_hands(hand_in: &Hand, hand_exp: &Hand) {
+        for (key, _) in hand_in {
+            assert_eq!(
+                "",
+                if hand_exp.contains_key(key) {
+                    "".to_string()
+                } else {
+                    format!("missing key: \"{}\"", key)
+                }
+            );
+        }
+
+        for (key, value) in hand_exp {
+            assert_hand_value(hand_in, key, *value);
+        }
+    }
+}
@@ -0,0 +1,232 @@
+// predates the workspace, kept in its original style
+#![allow(clippy::needless_return, clippy::redundant_closure)]
+
+use std::collections::{HashMap, HashSet};
+
+use aoc_shared::{split_lines, AocError, Grid, ParseError, Solution};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Part {
+    pub id: usize,
+    pub value: i32,
+}
+
+pub type PartMap = HashMap<(usize, usize), Part>;
+trait PartHolder {
+    fn insert_part(&mut self, x_range: (usize, usize), y: usize, part: Part);
+}
+
+impl PartHolder for PartMap {
+    fn insert_part(&mut self, (x_start, x_end): (usize, usize), y: usize, part: Part) {
+        for x in x_start..x_end {
+            self.insert((x, y), part);
+        }
+    }
+}
+
+pub fn make_part_map(grid: &Grid<char>) -> Result<PartMap, ParseError> {
+    let mut part_map = PartMap::new();
+    let mut next_id: usize = 0;
+
+    for (y, row) in grid.rows().enumerate() {
+        let mut x: usize = 0;
+        while x < row.len() {
+            if !row[x].is_ascii_digit() {
+                x += 1;
+                continue;
+            }
+
+            let num_start = x;
+            while x < row.len() && row[x].is_ascii_digit() {
+                x += 1;
+            }
+            let num_string: String = row[num_start..x].iter().collect();
+            let value = match num_string.parse() {
+                Ok(value) => value,
+                Err(_) => {
+                    let found = format!("\"{}\"", num_string);
+                    return Err(ParseError::new(
+                        y + 1,
+                        num_start + 1,
+                        "a part number",
+                        &found,
+                    ));
+                }
+            };
+            let part = Part { id: next_id, value };
+            part_map.insert_part((num_start, x), y, part);
+            next_id += 1;
+        }
+    }
+
+    return Ok(part_map);
+}
+
+fn is_symbol(c: char) -> bool {
+    return c != '.' && !c.is_ascii_digit();
+}
+
+pub fn count_parts(grid: &Grid<char>, part_map: &PartMap) -> i32 {
+    let mut part_sum: i32 = 0;
+    let mut visited_part_ids = HashSet::<usize>::new();
+    for pos in grid.positions(|c| is_symbol(*c)) {
+        for neighbor in grid.neighbors8(pos) {
+            if let Some(part) = part_map.get(&neighbor) {
+                if visited_part_ids.insert(part.id) {
+                    part_sum += part.value;
+                }
+            }
+        }
+    }
+    return part_sum;
+}
+
+pub fn gear_ratios(grid: &Grid<char>, part_map: &PartMap) -> i32 {
+    let mut ratio_sum: i32 = 0;
+    for pos in grid.positions(|c| *c == '*') {
+        let mut visited_part_ids = HashSet::<usize>::new();
+        let gears: Vec<i32> = grid
+            .neighbors8(pos)
+            .filter_map(|neighbor| part_map.get(&neighbor))
+            .filter(|part| visited_part_ids.insert(part.id))
+            .map(|part| part.value)
+            .collect();
+
+        if gears.len() != 2 {
+            continue;
+        }
+        ratio_sum += gears[0] * gears[1];
+    }
+    return ratio_sum;
+}
+
+pub fn main_input() -> Option<String> {
+    aoc_shared::resource_path!("main.txt")
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = (Grid<char>, PartMap);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let grid = Grid::parse(&split_lines(input), |c| c)?;
+        let part_map = make_part_map(&grid)?;
+        Ok((grid, part_map))
+    }
+
+    fn part1((grid, part_map): &Self::Input) -> Self::Part1 {
+        count_parts(grid, part_map)
+    }
+
+    fn part2((grid, part_map): &Self::Input) -> Self::Part2 {
+        gear_ratios(grid, part_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::{get_resource_lines, resource_path, split_lines, Grid, Solution};
+    use pretty_assertions::assert_eq;
+
+    use crate::{count_parts, make_part_map, Day3, Part, PartMap};
+
+    aoc_shared::answers_test!(Day3);
+
+    #[test]
+    fn sample1_part_map_test() {
+        let lines = get_resource_lines(&resource_path!("sample.txt").unwrap());
+        let part_map = make_part_map(&Grid::parse(&lines, |c| c).unwrap()).unwrap();
+
+        let part_exp = Part { id: 0, value: 467 };
+        check_part_for_coords(&part_map, part_exp, (0, 0), (2, 0));
+
+        let part_exp = Part { id: 1, value: 114 };
+        check_part_for_coords(&part_map, part_exp, (5, 0), (7, 0));
+    }
+
+    #[test]
+    fn edge_cases_test() {
+        // *@=%+$&/-#
+        let grid = Grid::parse(
+            &split_lines(concat!(
+                "......1.1......\n",
+                ".......*.......\n",
+                "......1.1......\n",
+                ".......1.......\n",
+                "......1*1......\n",
+                ".......1.......\n"
+            )),
+            |c| c,
+        )
+        .unwrap();
+
+        let part_map = make_part_map(&grid).unwrap();
+        assert_eq!(count_parts(&grid, &part_map), 8);
+    }
+
+    #[test]
+    fn grid_edges_test() {
+        let (grid, part_map) = Day3::parse("*12\n...\n7.*\n..3\n").unwrap();
+        assert_eq!(count_parts(&grid, &part_map), 15);
+        assert_eq!(Day3::part2(&(grid, part_map)), 0);
+
+        let (grid, part_map) = Day3::parse("2*\n.3\n").unwrap();
+        assert_eq!(Day3::part2(&(grid, part_map)), 6);
+    }
+
+    #[test]
+    fn ragged_input_test() {
+        assert_eq!(
+            Day3::parse("467..\n...*\n").unwrap_err().to_string(),
+            "2:5: expected a row of 5 cells, found end of line"
+        );
+    }
+
+    #[test]
+    fn part_number_overflow_test() {
+        let input = "..*.........\n.99999999999\n";
+        assert_eq!(
+            Day3::parse(input).unwrap_err().to_string(),
+            "2:2: expected a part number, found \"99999999999\""
+        );
+    }
+
+    fn check_part_for_coords(
+        part_map: &PartMap,
+        part_exp: Part,
+        start: (usize, usize),
+        end: (usize, usize),
+    ) {
+        for_each_point(start, end, |p| {
+            let part: Option<&Part> = part_map.get(&p);
+            let msg_prefix = format!("PartMap[({},{})]: ", p.0, p.1);
+            let msg_in = format!(
+                "{} {}",
+                msg_prefix,
+                match part {
+                    Some(part) => format!("{:#?}", part),
+                    None => "None".to_string(),
+                }
+            );
+            let msg_exp = format!("{} {:#?}", msg_prefix, part_exp);
+            assert_eq!(msg_in, msg_exp);
+        });
+    }
+
+    fn for_each_point<F>(start: (usize, usize), end: (usize, usize), mut action: F)
+    where
+        F: FnMut((usize, usize)),
+    {
+        let (start_x, start_y) = start;
+        let (end_x, end_y) = end;
+
+        // Create an iterator over the cartesian product of the ranges
+        let points = (start_x..=end_x).flat_map(move |x| (start_y..=end_y).map(move |y| (x, y)));
+
+        // Execute the action for each point
+        points.for_each(|point| action(point));
+    }
+}
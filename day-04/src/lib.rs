@@ -0,0 +1,163 @@
+// predates the workspace, kept in its original style
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+
+use std::collections::HashSet;
+
+use aoc_shared::{split_lines, AocError, ParseError, Solution};
+
+// (winning numbers, numbers you have)
+pub type Card = (Vec<i32>, Vec<i32>);
+
+pub fn parse_sample(lines: &Vec<String>) -> Result<Vec<Card>, ParseError> {
+    fn parse_numbers(line_no: usize, line: &str, numbers: &str) -> Result<Vec<i32>, ParseError> {
+        numbers
+            .split_whitespace()
+            .map(|s| match s.parse::<i32>() {
+                Ok(n) if n >= 0 => Ok(n),
+                _ => Err(ParseError::at(line_no, line, s, "a card number")),
+            })
+            .collect()
+    }
+
+    return lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let line_no = index + 1;
+            let numbers = match line.split_once(":") {
+                Some((_, numbers)) => numbers,
+                None => return Err(ParseError::end_of_line(line_no, line, "':'")),
+            };
+            let (front, back) = match numbers.split_once("|") {
+                Some(split) => split,
+                None => return Err(ParseError::end_of_line(line_no, line, "'|'")),
+            };
+            let front_numbers = parse_numbers(line_no, line, front)?;
+            let back_numbers = parse_numbers(line_no, line, back)?;
+            return Ok((front_numbers, back_numbers));
+        })
+        .collect();
+}
+
+pub fn count_winners(card: &[Card]) -> i32 {
+    card.iter()
+        .map(|card| {
+            let winner_set: HashSet<i32> = card.0.iter().cloned().collect();
+            let num_winners: u32 = card
+                .1
+                .iter()
+                .filter(|n| winner_set.contains(n))
+                .count()
+                .try_into()
+                .unwrap_or(0);
+            match num_winners {
+                0 => 0,
+                n => 2_i32.pow(n - 1),
+            }
+        })
+        .sum()
+}
+
+pub fn count_copies(card: &[Card]) -> i32 {
+    let wins: Vec<i32> = card
+        .iter()
+        .map(|card| {
+            let winner_set: HashSet<i32> = card.0.iter().cloned().collect();
+            card.1
+                .iter()
+                .filter(|n| winner_set.contains(n))
+                .count()
+                .try_into()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut copies = vec![1; wins.len()];
+
+    for (i, num_wins) in wins.iter().enumerate() {
+        for j in i + 1
+            ..=i.checked_add((*num_wins as u32).try_into().unwrap())
+                .unwrap()
+        {
+            if j >= copies.len() {
+                break;
+            }
+            copies[j] += copies[i];
+        }
+    }
+    return copies.iter().sum();
+}
+
+pub fn main_input() -> Option<String> {
+    aoc_shared::resource_path!("main.txt")
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_sample(&split_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        count_winners(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        count_copies(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::aoc_test;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    aoc_shared::answers_test!(Day4);
+
+    #[rustfmt::skip]
+    aoc_test!(parse_sample_test, "sample.txt", |lines| parse_sample(lines).unwrap(), vec![
+        (vec![41,48,83,86,17],vec![83,86,6,31,17,9,48,53]),
+        (vec![13,32,20,16,61],vec![61,30,68,82,17,32,24,19]),
+        (vec![1,21,53,59,44],vec![69,82,63,72,16,21,14,1]),
+        (vec![41,92,73,84,69],vec![59,84,76,51,58,5,54,83]),
+        (vec![87,83,26,28,32],vec![88,30,70,12,93,22,82,36]),
+        (vec![31,18,13,56,72],vec![74,77,10,23,35,67,36,11]),
+    ]);
+
+    #[test]
+    fn parse_errors_test() {
+        let lines = vec![
+            "Card 1: 41 48 | 83 86".to_string(),
+            "Card 2: 13 x2 | 61 30".to_string(),
+        ];
+        assert_eq!(
+            parse_sample(&lines).err(),
+            Some(ParseError::new(2, 12, "a card number", "\"x2\""))
+        );
+
+        let lines = vec!["Card 1: 41 48 | 83 -86".to_string()];
+        assert_eq!(
+            parse_sample(&lines).err(),
+            Some(ParseError::new(1, 20, "a card number", "\"-86\""))
+        );
+
+        let lines = vec!["Card 1: 41 48 83 86".to_string()];
+        assert_eq!(
+            parse_sample(&lines).err(),
+            Some(ParseError::new(1, 20, "'|'", "end of line"))
+        );
+
+        let lines = vec!["Card 1 41 48 | 83 86".to_string()];
+        assert_eq!(
+            parse_sample(&lines).err(),
+            Some(ParseError::new(1, 21, "':'", "end of line"))
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use std::{collections::HashMap, fmt};
+
+use aoc_shared::{IntervalSet, Unsigned};
+
+use crate::{
+    chain_map_ranges, chain_preimage_ranges, find_mapping, rule_source, ParsedMap, PiecewiseMap,
+};
+
+/// Category the almanac's `seeds:` line is in, and so the source of the
+/// first map.
+pub const FIRST_CATEGORY: &str = "seed";
+
+/// A map that can't be part of the chain, because of its title or, for
+/// `CategoryGraph`, its rules. `index` is the position of the map in the
+/// almanac.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChainError {
+    pub index: usize,
+    pub title: String,
+    pub expected: String,
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "map {}: expected {}, found \"{}\"",
+            self.index + 1,
+            self.expected,
+            self.title
+        )
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+/// `"seed-to-soil"` -> `("seed", "soil")`
+pub fn split_title(title: &str) -> Option<(&str, &str)> {
+    match title.split_once("-to-") {
+        Some((source, target)) if !source.is_empty() && !target.is_empty() => {
+            Some((source, target))
+        }
+        _ => None,
+    }
+}
+
+/// Checks that every map goes from the category the previous one went to,
+/// starting at `seed`, and never comes back to a category. Returns the
+/// categories in the order the chain visits them.
+pub fn check_chain<T>(maps: &[ParsedMap<T>]) -> Result<Vec<String>, ChainError> {
+    let mut categories = vec![FIRST_CATEGORY.to_string()];
+    for (index, (title, _)) in maps.iter().enumerate() {
+        let error = |expected: String| ChainError {
+            index,
+            title: title.clone(),
+            expected,
+        };
+        let (source, target) =
+            split_title(title).ok_or_else(|| error("\"<source>-to-<target>\"".to_string()))?;
+
+        let previous = categories.last().unwrap();
+        if source != previous {
+            return Err(error(format!("a map from \"{}\"", previous)));
+        }
+        if categories.iter().any(|c| c == target) {
+            return Err(error(format!(
+                "a map from \"{}\" to a new category",
+                previous
+            )));
+        }
+        categories.push(target.to_string());
+    }
+    return Ok(categories);
+}
+
+/// The almanac's maps as edges between categories, so values can be
+/// converted between any two of them. Only holds rules that fit in `T`, so
+/// conversions can't overflow.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CategoryGraph<T = usize> {
+    categories: Vec<String>,
+    index: HashMap<String, usize>,
+    maps: Vec<ParsedMap<T>>,
+}
+
+const CHECKED: &str = "rules are checked by CategoryGraph::new";
+
+impl<T: Unsigned> CategoryGraph<T> {
+    pub fn new(maps: Vec<ParsedMap<T>>) -> Result<CategoryGraph<T>, ChainError> {
+        let categories = check_chain(&maps)?;
+        for (index, (title, rules)) in maps.iter().enumerate() {
+            if rules.iter().any(|rule| rule_source(*rule).is_none()) {
+                return Err(ChainError {
+                    index,
+                    title: title.clone(),
+                    expected: format!("rules within {}", T::NAME),
+                });
+            }
+        }
+        let index = categories
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.clone(), i))
+            .collect();
+        Ok(CategoryGraph {
+            categories,
+            index,
+            maps,
+        })
+    }
+
+    /// Categories in chain order, starting with `seed`.
+    pub fn categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    /// The maps leading from `from` to `to`, `None` if either category is
+    /// unknown or `to` comes before `from`.
+    pub fn path(&self, from: &str, to: &str) -> Option<&[ParsedMap<T>]> {
+        let (from, to) = (*self.index.get(from)?, *self.index.get(to)?);
+        match from <= to {
+            true => Some(&self.maps[from..to]),
+            false => None,
+        }
+    }
+
+    pub fn convert(&self, from: &str, to: &str, value: T) -> Option<T> {
+        let path = self.path(from, to)?;
+        let converted = path
+            .iter()
+            .try_fold(value, |acc, m| find_mapping(&m.1, acc));
+        Some(converted.expect(CHECKED))
+    }
+
+    /// Converts a set of values; going backwards (`location` to `seed`, ...)
+    /// gives every value that ends up in `values`.
+    pub fn convert_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        values: &IntervalSet<T>,
+    ) -> Option<IntervalSet<T>> {
+        if let Some(path) = self.path(from, to) {
+            return Some(chain_map_ranges(values, &path.to_vec()).expect(CHECKED));
+        }
+        let path = self.path(to, from)?;
+        Some(chain_preimage_ranges(values, &path.to_vec()).expect(CHECKED))
+    }
+
+    pub fn compose(&self, from: &str, to: &str) -> Option<PiecewiseMap<T>> {
+        let path = self.path(from, to)?.to_vec();
+        Some(PiecewiseMap::from_chain(&path).expect(CHECKED))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::{get_resource_lines, resource_path, Interval};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::parse_problem;
+
+    fn maps(titles: &[&str]) -> Vec<ParsedMap> {
+        titles.iter().map(|t| (t.to_string(), vec![])).collect()
+    }
+
+    fn sample() -> CategoryGraph {
+        let lines = get_resource_lines(&resource_path!("sample.txt").unwrap());
+        CategoryGraph::new(parse_problem(&lines).unwrap().1).unwrap()
+    }
+
+    #[test]
+    fn split_title_test() {
+        assert_eq!(split_title("seed-to-soil"), Some(("seed", "soil")));
+        assert_eq!(split_title("seed-soil"), None);
+        assert_eq!(split_title("seed-to-"), None);
+        assert_eq!(split_title("-to-soil"), None);
+    }
+
+    #[test]
+    fn check_chain_test() {
+        assert_eq!(
+            check_chain(&maps(&["seed-to-soil", "soil-to-water"])),
+            Ok(vec![
+                "seed".to_string(),
+                "soil".to_string(),
+                "water".to_string()
+            ])
+        );
+        assert_eq!(check_chain(&maps(&[])), Ok(vec!["seed".to_string()]));
+
+        let error = |index: usize, title: &str, expected: &str| {
+            Err(ChainError {
+                index,
+                title: title.to_string(),
+                expected: expected.to_string(),
+            })
+        };
+        assert_eq!(
+            check_chain(&maps(&["soil-to-water"])),
+            error(0, "soil-to-water", "a map from \"seed\"")
+        );
+        // out of order
+        assert_eq!(
+            check_chain(&maps(&["seed-to-soil", "water-to-light", "soil-to-water"])),
+            error(1, "water-to-light", "a map from \"soil\"")
+        );
+        assert_eq!(
+            check_chain(&maps(&["seed-to-soil", "soil-to-seed"])),
+            error(1, "soil-to-seed", "a map from \"soil\" to a new category")
+        );
+        assert_eq!(
+            check_chain(&maps(&["seed-to-soil", "soil"])),
+            error(1, "soil", "\"<source>-to-<target>\"")
+        );
+        assert_eq!(
+            error(1, "soil", "\"<source>-to-<target>\"")
+                .unwrap_err()
+                .to_string(),
+            "map 2: expected \"<source>-to-<target>\", found \"soil\""
+        );
+    }
+
+    #[test]
+    fn overflowing_rules_test() {
+        let mut maps: Vec<ParsedMap<u32>> = vec![
+            ("seed-to-soil".to_string(), vec![(0, 10, 5)]),
+            ("soil-to-water".to_string(), vec![(u32::MAX - 1, 0, 3)]),
+        ];
+        assert_eq!(
+            CategoryGraph::new(maps.clone()).unwrap_err().to_string(),
+            "map 2: expected rules within u32, found \"soil-to-water\""
+        );
+
+        // the last value moved to is u32::MAX itself
+        maps[1].1[0] = (u32::MAX - 1, 0, 2);
+        let graph = CategoryGraph::new(maps).unwrap();
+        assert_eq!(graph.convert("soil", "water", 1), Some(u32::MAX));
+        assert_eq!(graph.convert("seed", "water", 11), Some(u32::MAX));
+        assert_eq!(graph.convert("seed", "water", u32::MAX), Some(u32::MAX));
+    }
+
+    #[test]
+    fn categories_test() {
+        assert_eq!(
+            sample().categories(),
+            &[
+                "seed",
+                "soil",
+                "fertilizer",
+                "water",
+                "light",
+                "temperature",
+                "humidity",
+                "location"
+            ]
+        );
+    }
+
+    #[test]
+    fn convert_test() {
+        let graph = sample();
+        // from the puzzle's walkthrough of seed 79
+        assert_eq!(graph.convert("seed", "soil", 79), Some(81));
+        assert_eq!(graph.convert("soil", "humidity", 81), Some(78));
+        assert_eq!(graph.convert("seed", "location", 79), Some(82));
+        assert_eq!(graph.convert("water", "water", 5), Some(5));
+        assert_eq!(graph.convert("location", "seed", 82), None);
+        assert_eq!(graph.convert("seed", "planet", 82), None);
+
+        let composed = graph.compose("soil", "humidity").unwrap();
+        assert_eq!(composed.get(81), 78);
+        assert_eq!(graph.path("light", "humidity").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn convert_ranges_test() {
+        let graph = sample();
+        let seeds = IntervalSet::from(Interval::new(79, 93));
+        let locations = graph.convert_ranges("seed", "location", &seeds).unwrap();
+        assert_eq!(locations.min(), Some(46));
+
+        let back = graph
+            .convert_ranges("location", "seed", &locations)
+            .unwrap();
+        assert!(seeds.difference(&back).is_empty());
+    }
+}
@@ -0,0 +1,306 @@
+//! Randomized differential testing of the range based implementations
+//! against an oracle mapping every seed on its own through `chain_mappings`.
+
+use aoc_shared::{shrink, split_lines, Interval, IntervalSet, Overflow, Rng};
+use pretty_assertions::assert_eq;
+
+use crate::{
+    chain_map_ranges, chain_mappings, parse_problem, write_problem, ParsedMap, PiecewiseMap,
+    FIRST_CATEGORY,
+};
+
+type Implementation = fn(&IntervalSet, &Vec<ParsedMap>) -> Result<IntervalSet, Overflow>;
+
+const IMPLEMENTATIONS: [(&str, Implementation); 2] = [
+    ("chain_map_ranges", chain_map_ranges),
+    ("PiecewiseMap", |seeds, mappings| {
+        Ok(PiecewiseMap::from_chain(mappings)?.map_set(seeds))
+    }),
+];
+
+/// A small almanac: seed ranges as `(start, len)` and the rules of each map.
+#[derive(Debug, Clone, PartialEq)]
+struct Case {
+    seeds: Vec<(usize, usize)>,
+    maps: Vec<Vec<(usize, usize, usize)>>,
+}
+
+impl Case {
+    /// Values stay small so rules and seed ranges run into each other a lot.
+    fn random(rng: &mut Rng) -> Case {
+        let seeds = (0..rng.range(1..4))
+            .map(|_| (rng.range(0..64), rng.range(1..16)))
+            .collect();
+        let maps = (0..rng.range(1..5))
+            .map(|_| {
+                (0..rng.range(0..5))
+                    .map(|_| (rng.range(0..64), rng.range(0..64), rng.range(0..16)))
+                    .collect()
+            })
+            .collect();
+        Case { seeds, maps }
+    }
+
+    fn mappings(&self) -> Vec<ParsedMap> {
+        let category = |i: usize| match i {
+            0 => FIRST_CATEGORY.to_string(),
+            _ => format!("c{}", i),
+        };
+        self.maps
+            .iter()
+            .enumerate()
+            .map(|(i, rules)| {
+                (
+                    format!("{}-to-{}", category(i), category(i + 1)),
+                    rules.clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// The seeds line as `parse_problem` returns it.
+    fn seed_values(&self) -> Vec<usize> {
+        self.seeds
+            .iter()
+            .flat_map(|(start, len)| [*start, *len])
+            .collect()
+    }
+
+    /// The case as an almanac, ready to be saved into `resources/`.
+    fn almanac(&self) -> String {
+        write_problem(&self.seed_values(), &self.mappings())
+    }
+
+    fn seed_ranges(&self) -> IntervalSet {
+        self.seeds
+            .iter()
+            .map(|(start, len)| Interval::from_len(*start, *len))
+            .collect()
+    }
+
+    /// Cases with one seed range, map or rule removed or made smaller.
+    fn smaller(&self) -> Vec<Case> {
+        let mut cases = Vec::<Case>::new();
+        let lower = |n: usize| -> Vec<usize> {
+            match n {
+                0 => vec![],
+                1 => vec![0],
+                _ => vec![n / 2, n - 1],
+            }
+        };
+
+        for i in 0..self.seeds.len() {
+            let mut case = self.clone();
+            case.seeds.remove(i);
+            cases.push(case);
+        }
+        for m in 0..self.maps.len() {
+            let mut case = self.clone();
+            case.maps.remove(m);
+            cases.push(case);
+            for r in 0..self.maps[m].len() {
+                let mut case = self.clone();
+                case.maps[m].remove(r);
+                cases.push(case);
+            }
+        }
+
+        for (i, (start, len)) in self.seeds.iter().enumerate() {
+            for start in lower(*start) {
+                let mut case = self.clone();
+                case.seeds[i].0 = start;
+                cases.push(case);
+            }
+            for len in lower(*len).into_iter().filter(|l| *l > 0) {
+                let mut case = self.clone();
+                case.seeds[i].1 = len;
+                cases.push(case);
+
+                // same end, later start
+                let mut case = self.clone();
+                case.seeds[i] = (start + (self.seeds[i].1 - len), len);
+                cases.push(case);
+            }
+        }
+        for (m, rules) in self.maps.iter().enumerate() {
+            for (r, (dst, src, len)) in rules.iter().enumerate() {
+                for dst in lower(*dst) {
+                    let mut case = self.clone();
+                    case.maps[m][r].0 = dst;
+                    cases.push(case);
+                }
+                for src in lower(*src) {
+                    let mut case = self.clone();
+                    case.maps[m][r].1 = src;
+                    cases.push(case);
+                }
+                for len in lower(*len) {
+                    let mut case = self.clone();
+                    case.maps[m][r].2 = len;
+                    cases.push(case);
+
+                    let trimmed = self.maps[m][r].2 - len;
+                    let mut case = self.clone();
+                    case.maps[m][r] = (dst + trimmed, src + trimmed, len);
+                    cases.push(case);
+                }
+            }
+        }
+        return cases;
+    }
+}
+
+/// Every location reached from a seed, one seed at a time.
+fn oracle(case: &Case) -> IntervalSet {
+    let seeds: Vec<usize> = case
+        .seeds
+        .iter()
+        .flat_map(|(start, len)| *start..start + len)
+        .collect();
+    chain_mappings(&seeds, case.mappings())
+        .unwrap()
+        .into_iter()
+        .map(|location| Interval::from_len(location, 1))
+        .collect()
+}
+
+fn check(case: &Case, implementation: Implementation) -> Result<(), String> {
+    let expected = oracle(case);
+    let got = implementation(&case.seed_ranges(), &case.mappings()).map_err(|e| e.to_string())?;
+    if got.min() != expected.min() {
+        return Err(format!(
+            "lowest location: expected {:?}, got {:?}",
+            expected.min(),
+            got.min()
+        ));
+    }
+    if got != expected {
+        return Err(format!("locations: expected {}, got {}", expected, got));
+    }
+    return Ok(());
+}
+
+/// Checks `cases` random almanacs, returning the shrunk first failure.
+fn run(
+    name: &str,
+    implementation: Implementation,
+    seed: u64,
+    cases: usize,
+) -> Result<(), (Case, String)> {
+    let mut rng = Rng::new(seed);
+    for n in 0..cases {
+        let case = Case::random(&mut rng);
+        if check(&case, implementation).is_err() {
+            let minimal = shrink(case, Case::smaller, |c| check(c, implementation).is_err());
+            let message = format!(
+                "{} disagrees with the oracle (seed {}, case {}): {}\nminimal counterexample:\n{}",
+                name,
+                seed,
+                n,
+                check(&minimal, implementation).unwrap_err(),
+                minimal.almanac()
+            );
+            return Err((minimal, message));
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn oracle_test() {
+    let case = Case {
+        seeds: vec![(79, 14), (55, 13)],
+        maps: vec![vec![(50, 98, 2), (52, 50, 48)]],
+    };
+    assert_eq!(
+        oracle(&case),
+        IntervalSet::from_iter([Interval::new(57, 70), Interval::new(81, 95)])
+    );
+}
+
+#[test]
+fn differential_test() {
+    for (name, implementation) in IMPLEMENTATIONS {
+        for seed in 0..4 {
+            if let Err((_, message)) = run(name, implementation, seed, 500) {
+                panic!("{}", message);
+            }
+        }
+    }
+}
+
+#[test]
+fn shrinks_counterexample_test() {
+    // inclusive rule ends, the bug find_mapping used to have
+    let off_by_one: Implementation = |seeds, mappings| {
+        let widened: Vec<ParsedMap> = mappings
+            .iter()
+            .map(|(title, rules)| {
+                let rules = rules.iter().map(|(d, s, l)| (*d, *s, l + 1)).collect();
+                (title.clone(), rules)
+            })
+            .collect();
+        chain_map_ranges(seeds, &widened)
+    };
+
+    let (minimal, message) = run("off_by_one", off_by_one, 0, 500).unwrap_err();
+    assert!(message.starts_with("off_by_one disagrees with the oracle"));
+    assert_eq!(minimal.seeds.len(), 1);
+    assert_eq!(minimal.seeds[0].1, 1);
+    assert_eq!(minimal.maps.len(), 1);
+    assert_eq!(minimal.maps[0].len(), 1);
+    assert!(check(&minimal, off_by_one).is_err());
+    assert!(minimal
+        .smaller()
+        .iter()
+        .all(|c| check(c, off_by_one).is_ok()));
+}
+
+#[test]
+fn round_trip_test() {
+    let mut rng = Rng::new(0);
+    for _ in 0..500 {
+        let case = Case::random(&mut rng);
+        let almanac = case.almanac();
+        let parsed = parse_problem(&split_lines(&almanac));
+        assert_eq!(
+            parsed,
+            Ok((case.seed_values(), case.mappings())),
+            "{}",
+            almanac
+        );
+
+        let (seeds, maps) = parsed.unwrap();
+        assert_eq!(write_problem(&seeds, &maps), almanac);
+    }
+}
+
+#[test]
+fn wide_round_trip_test() {
+    // values spread over the whole of u64, rules kept within it
+    let mut rng = Rng::new(1);
+    for _ in 0..100 {
+        let mut value = || rng.next_u64() >> rng.range(0..64);
+        let seeds: Vec<u64> = (0..6).map(|_| value()).collect();
+        let maps: Vec<ParsedMap<u64>> = ["seed-to-soil", "soil-to-location"]
+            .iter()
+            .map(|title| {
+                let rules = (0..4)
+                    .map(|_| {
+                        let len = value() >> 1;
+                        (value() % (u64::MAX - len), value() % (u64::MAX - len), len)
+                    })
+                    .collect();
+                (title.to_string(), rules)
+            })
+            .collect();
+
+        let almanac = write_problem(&seeds, &maps);
+        assert_eq!(
+            parse_problem(&split_lines(&almanac)),
+            Ok((seeds, maps)),
+            "{}",
+            almanac
+        );
+    }
+}
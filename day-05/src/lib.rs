@@ -0,0 +1,803 @@
+// predates the workspace, kept in its original style
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+
+mod almanac;
+#[cfg(test)]
+mod differential;
+mod piecewise;
+mod trace;
+mod validate;
+
+use std::fmt::Write;
+
+pub use almanac::{check_chain, split_title, CategoryGraph, ChainError, FIRST_CATEGORY};
+use aoc_shared::{
+    parse_at, split_lines, AocError, Interval, IntervalSet, Overflow, ParseError, Solution,
+    Unsigned,
+};
+pub use piecewise::{PiecewiseMap, Segment};
+pub use trace::{RangeTrace, Split, StageTrace, Trace};
+pub use validate::{rule_source, validate_almanac, validate_map, MapIssue};
+
+pub type ParsedMap<T = usize> = (String, Vec<(T, T, T)>);
+pub fn parse_problem<T: Unsigned>(
+    lines: &Vec<String>,
+) -> Result<(Vec<T>, Vec<ParsedMap<T>>), ParseError> {
+    //(seeds, maps)
+    let seed_line = match lines.first() {
+        Some(line) => line,
+        None => return Err(ParseError::new(1, 1, "\"seeds:\"", "end of input")),
+    };
+    let seeds_str = match seed_line.split_once(':') {
+        Some(("seeds", seeds_str)) => seeds_str,
+        _ => return Err(ParseError::at(1, seed_line, seed_line, "\"seeds:\"")),
+    };
+    let seed_strs: Vec<&str> = seeds_str.split_whitespace().collect();
+    let seeds: Vec<T> = seed_strs
+        .iter()
+        .map(|s| parse_at(1, seed_line, s, "a seed number"))
+        .collect::<Result<_, _>>()?;
+    // part 2 reads the seeds as (start, length) ranges
+    for (i, pair) in seeds.chunks_exact(2).enumerate() {
+        if Interval::try_from_len(pair[0], pair[1]).is_err() {
+            let expected = format!("a seed range within {}", T::NAME);
+            return Err(ParseError::at(
+                1,
+                seed_line,
+                seed_strs[2 * i + 1],
+                &expected,
+            ));
+        }
+    }
+
+    let mut maps = Vec::<ParsedMap<T>>::new();
+    let mut in_map = false;
+    for (index, line) in lines.iter().enumerate().skip(1) {
+        let line_no = index + 1;
+        if line.trim().is_empty() {
+            in_map = false;
+            continue;
+        }
+
+        if line.contains(':') {
+            let title = line.split_whitespace().next().unwrap_or("");
+            if title.is_empty() || !line.trim_end().ends_with("map:") {
+                return Err(ParseError::at(line_no, line, line, "\"<name> map:\""));
+            }
+            maps.push((title.to_string(), Vec::<_>::new()));
+            check_chain(&maps).map_err(|e| ParseError::at(line_no, line, title, &e.expected))?;
+            in_map = true;
+            continue;
+        }
+
+        if !in_map {
+            return Err(ParseError::at(line_no, line, line, "a map title"));
+        }
+
+        let values: Vec<T> = line
+            .split_whitespace()
+            .map(|n| parse_at(line_no, line, n, "a number"))
+            .collect::<Result<_, _>>()?;
+        let found = line.trim_end();
+        if values.len() != 3 {
+            return Err(ParseError::at(line_no, line, found, "3 numbers"));
+        }
+        let rule = (values[0], values[1], values[2]);
+        if rule_source(rule).is_none() {
+            let expected = format!("a rule within {}", T::NAME);
+            return Err(ParseError::at(line_no, line, found, &expected));
+        }
+        maps.last_mut().unwrap().1.push(rule);
+    }
+
+    return Ok((seeds, maps));
+}
+
+/// The almanac text `parse_problem` reads back as `(seeds, maps)`.
+pub fn write_problem<T: Unsigned>(seeds: &Vec<T>, maps: &Vec<ParsedMap<T>>) -> String {
+    let mut out = String::from("seeds:");
+    for seed in seeds {
+        write!(out, " {}", seed).unwrap();
+    }
+    out.push('\n');
+    for (title, rules) in maps {
+        write!(out, "\n{} map:\n", title).unwrap();
+        for (dst, src, len) in rules {
+            writeln!(out, "{} {} {}", dst, src, len).unwrap();
+        }
+    }
+    return out;
+}
+
+/// Rules cover the half-open `src..src + len`; the first one containing
+/// `value` moves it. Only fails for rules whose destination overflows, which
+/// `parse_problem` never returns.
+pub fn find_mapping<T: Unsigned>(map: &Vec<(T, T, T)>, value: T) -> Result<T, Overflow> {
+    for (dst, src, len) in map {
+        if value >= *src && value - *src < *len {
+            return dst.try_add(value - *src);
+        }
+    }
+    return Ok(value);
+}
+
+/// Every value `find_mapping` sends to `value`, in ascending order.
+pub fn find_preimage<T: Unsigned>(map: &Vec<(T, T, T)>, value: T) -> Result<Vec<T>, Overflow> {
+    let target = IntervalSet::from(Interval::try_from_len(value, T::ONE)?);
+    let mut values = Vec::<T>::new();
+    for interval in PiecewiseMap::from_rules(map)?.preimage(&target).iter() {
+        let mut value = interval.start;
+        values.push(value);
+        while value < interval.last() {
+            value = value + T::ONE;
+            values.push(value);
+        }
+    }
+    return Ok(values);
+}
+
+pub fn preimage_ranges<T: Unsigned>(
+    target: &IntervalSet<T>,
+    mapping: &ParsedMap<T>,
+) -> Result<IntervalSet<T>, Overflow> {
+    Ok(PiecewiseMap::from_map(mapping)?.preimage(target))
+}
+
+/// Every seed whose location lies in `target`.
+pub fn chain_preimage_ranges<T: Unsigned>(
+    target: &IntervalSet<T>,
+    mappings: &Vec<ParsedMap<T>>,
+) -> Result<IntervalSet<T>, Overflow> {
+    mappings
+        .iter()
+        .rev()
+        .try_fold(target.clone(), |acc, m| preimage_ranges(&acc, m))
+}
+
+pub fn chain_mappings<T: Unsigned>(
+    seeds: &Vec<T>,
+    mappings: Vec<ParsedMap<T>>,
+) -> Result<Vec<T>, Overflow> {
+    return seeds
+        .iter()
+        .map(|s| {
+            mappings
+                .iter()
+                .try_fold(*s, |acc, mapping| find_mapping(&mapping.1, acc))
+        })
+        .collect();
+}
+
+pub fn seed_vec_to_ranges<T: Unsigned>(seeds: &Vec<T>) -> Result<IntervalSet<T>, Overflow> {
+    seeds
+        .chunks_exact(2)
+        .map(|pair| Interval::try_from_len(pair[0], pair[1]))
+        .collect()
+}
+
+/// Applies one map to every value in `ranges_in`. Rules are tried in order,
+/// so a value is moved by the first rule whose source contains it; values no
+/// rule covers keep their number.
+pub fn map_ranges<T: Unsigned>(
+    ranges_in: &IntervalSet<T>,
+    mapping: &ParsedMap<T>,
+) -> Result<IntervalSet<T>, Overflow> {
+    map_ranges_traced(ranges_in, mapping, None)
+}
+
+/// `map_ranges`, recording how every input range was split into `trace`.
+pub fn map_ranges_traced<T: Unsigned>(
+    ranges_in: &IntervalSet<T>,
+    mapping: &ParsedMap<T>,
+    mut trace: Option<&mut StageTrace<T>>,
+) -> Result<IntervalSet<T>, Overflow> {
+    let mut mapped = IntervalSet::new();
+    for input in ranges_in {
+        let mut range_trace = RangeTrace {
+            input: *input,
+            splits: vec![],
+            unmatched: vec![],
+        };
+        let mut remaining = vec![*input];
+        for (rule, (dst, src, len)) in mapping.1.iter().enumerate() {
+            let source = Interval::try_from_len(*src, *len)?;
+            let mut missed = Vec::<Interval<T>>::new();
+            for piece in remaining {
+                let middle = match piece.intersection(&source) {
+                    Some(middle) => middle,
+                    None => {
+                        missed.push(piece);
+                        continue;
+                    }
+                };
+                let (left, right) = piece.difference(&source);
+                let target = middle.try_shift(*src, *dst)?;
+                mapped.insert(target);
+                missed.extend(left);
+                missed.extend(right);
+                range_trace.splits.push(Split {
+                    rule,
+                    piece,
+                    left,
+                    middle,
+                    right,
+                    mapped: target,
+                });
+            }
+            remaining = missed;
+        }
+
+        for piece in &remaining {
+            mapped.insert(*piece);
+        }
+        if let Some(trace) = trace.as_deref_mut() {
+            range_trace.unmatched = remaining;
+            trace.ranges.push(range_trace);
+        }
+    }
+
+    if let Some(trace) = trace {
+        trace.output = mapped.intervals().to_vec();
+    }
+    return Ok(mapped);
+}
+
+pub fn chain_map_ranges<T: Unsigned>(
+    seed_ranges: &IntervalSet<T>,
+    mappings: &Vec<ParsedMap<T>>,
+) -> Result<IntervalSet<T>, Overflow> {
+    mappings
+        .iter()
+        .try_fold(seed_ranges.clone(), |acc, m| map_ranges(&acc, m))
+}
+
+pub fn chain_map_ranges_traced<T: Unsigned>(
+    seed_ranges: &IntervalSet<T>,
+    mappings: &Vec<ParsedMap<T>>,
+) -> Result<(IntervalSet<T>, Trace<T>), Overflow> {
+    let mut trace = Trace::default();
+    let mut ranges = seed_ranges.clone();
+    for mapping in mappings {
+        let mut stage = StageTrace::new(&mapping.0);
+        ranges = map_ranges_traced(&ranges, mapping, Some(&mut stage))?;
+        trace.stages.push(stage);
+    }
+    return Ok((ranges, trace));
+}
+
+pub fn main_input() -> Option<String> {
+    aoc_shared::resource_path!("main.txt")
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Vec<u64>, Vec<ParsedMap<u64>>);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // seed ranges and rules are checked by parse_problem
+        let (seeds, mappings) = parse_problem(&split_lines(input))?;
+        Ok((seeds, mappings))
+    }
+
+    fn part1((seeds, mappings): &Self::Input) -> Self::Part1 {
+        chain_mappings(seeds, mappings.clone())
+            .unwrap()
+            .into_iter()
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn part2((seeds, mappings): &Self::Input) -> Self::Part2 {
+        let seed_ranges = seed_vec_to_ranges(seeds).unwrap();
+        chain_map_ranges(&seed_ranges, mappings)
+            .unwrap()
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::{get_resource_lines, read_file, resource_path};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    aoc_shared::answers_test!(Day5);
+
+    #[test]
+    fn find_mapping_test() {
+        let mapping: Vec<(usize, usize, usize)> = vec![(12, 10, 4), (199, 99, 10)];
+        assert_eq!(find_mapping(&mapping, 1), Ok(1));
+        assert_eq!(find_mapping(&mapping, 10), Ok(12));
+        assert_eq!(find_mapping(&mapping, 11), Ok(13));
+        assert_eq!(find_mapping(&mapping, 12), Ok(14));
+        assert_eq!(find_mapping(&mapping, 13), Ok(15));
+        assert_eq!(find_mapping(&mapping, 14), Ok(14));
+        assert_eq!(find_mapping(&mapping, 15), Ok(15));
+
+        assert_eq!(find_mapping(&mapping, 98), Ok(98));
+        assert_eq!(find_mapping(&mapping, 99), Ok(199));
+        assert_eq!(find_mapping(&mapping, 108), Ok(208));
+        assert_eq!(find_mapping(&mapping, 109), Ok(109));
+        assert_eq!(find_mapping(&mapping, 110), Ok(110));
+
+        let mapping: Vec<(usize, usize, usize)> = vec![(50, 98, 2), (52, 50, 48)];
+        assert_eq!(find_mapping(&mapping, 0), Ok(0));
+        assert_eq!(find_mapping(&mapping, 1), Ok(1));
+        assert_eq!(find_mapping(&mapping, 48), Ok(48));
+        assert_eq!(find_mapping(&mapping, 49), Ok(49));
+        assert_eq!(find_mapping(&mapping, 50), Ok(52));
+        assert_eq!(find_mapping(&mapping, 51), Ok(53));
+        assert_eq!(find_mapping(&mapping, 96), Ok(98));
+        assert_eq!(find_mapping(&mapping, 97), Ok(99));
+        assert_eq!(find_mapping(&mapping, 98), Ok(50));
+        assert_eq!(find_mapping(&mapping, 99), Ok(51));
+    }
+
+    #[test]
+    fn find_mapping_edges_test() {
+        let max = usize::MAX;
+        let mapping: Vec<(usize, usize, usize)> = vec![(0, max - 2, 2), (100, 10, 0), (7, 5, 1)];
+        assert_eq!(find_mapping(&mapping, max - 3), Ok(max - 3));
+        assert_eq!(find_mapping(&mapping, max - 2), Ok(0));
+        assert_eq!(find_mapping(&mapping, max - 1), Ok(1));
+        assert_eq!(find_mapping(&mapping, max), Ok(max));
+
+        // zero length rules never match, length one rules match once
+        assert_eq!(find_mapping(&mapping, 10), Ok(10));
+        assert_eq!(find_mapping(&mapping, 4), Ok(4));
+        assert_eq!(find_mapping(&mapping, 5), Ok(7));
+        assert_eq!(find_mapping(&mapping, 6), Ok(6));
+
+        // same semantics as the interval based implementations
+        let lines = get_resource_lines(&resource_path!("main.txt").unwrap());
+        let (_, mappings) = parse_problem::<usize>(&lines).unwrap();
+        for (_, rules) in &mappings {
+            let piecewise = PiecewiseMap::from_rules(rules).unwrap();
+            for (_, src, len) in rules {
+                for value in [src.saturating_sub(1), *src, src + len - 1, src + len] {
+                    assert_eq!(find_mapping(rules, value), Ok(piecewise.get(value)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn find_preimage_test() {
+        let mapping: Vec<(usize, usize, usize)> = vec![(50, 98, 2), (52, 50, 48)];
+        assert_eq!(find_preimage(&mapping, 1), Ok(vec![1]));
+        assert_eq!(find_preimage(&mapping, 50), Ok(vec![98]));
+        assert_eq!(find_preimage(&mapping, 52), Ok(vec![50]));
+        assert_eq!(find_preimage(&mapping, 99), Ok(vec![97]));
+        assert_eq!(find_preimage(&mapping, 100), Ok(vec![100]));
+
+        let mapping: Vec<(usize, usize, usize)> = vec![(12, 10, 4), (10, 20, 2)];
+        assert_eq!(find_preimage(&mapping, 10), Ok(vec![20]));
+        assert_eq!(find_preimage(&mapping, 12), Ok(vec![10]));
+        assert_eq!(find_preimage(&mapping, 14), Ok(vec![12, 14]));
+        assert_eq!(find_preimage(&mapping, 20), Ok(Vec::<usize>::new()));
+    }
+
+    #[test]
+    fn chain_preimage_test() {
+        for (name, lowest) in [("sample.txt", 46), ("main.txt", 125742456)] {
+            let lines = get_resource_lines(&resource_path!(name).unwrap());
+            let (seeds, mappings) = parse_problem(&lines).unwrap();
+            let seed_ranges = seed_vec_to_ranges(&seeds).unwrap();
+
+            // the part 2 answer is the lowest location reachable from a seed
+            let below = IntervalSet::from(Interval::new(0, lowest));
+            let at = IntervalSet::from(Interval::from_len(lowest, 1));
+            let reaching = |target: &IntervalSet| {
+                chain_preimage_ranges(target, &mappings)
+                    .unwrap()
+                    .intersection(&seed_ranges)
+            };
+            assert!(reaching(&below).is_empty(), "{}", name);
+            assert_eq!(reaching(&at).count(), 1, "{}", name);
+
+            let composed = PiecewiseMap::from_chain(&mappings).unwrap();
+            for seed in seeds {
+                let location = IntervalSet::from(Interval::from_len(composed.get(seed), 1));
+                assert!(chain_preimage_ranges(&location, &mappings)
+                    .unwrap()
+                    .contains(seed));
+            }
+            assert_eq!(
+                chain_preimage_ranges(&below, &mappings).unwrap(),
+                composed.preimage(&below),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn sample_parse_test() {
+        let lines = get_resource_lines(&resource_path!("sample.txt").unwrap());
+        let parsed = parse_problem::<usize>(&lines).unwrap();
+        assert_eq!(parsed.0, vec![79, 14, 55, 13]);
+
+        assert_eq!(parsed.1[0].0, "seed-to-soil");
+        assert_eq!(parsed.1[0].1, vec![(50, 98, 2), (52, 50, 48)]);
+
+        assert_eq!(parsed.1[1].0, "soil-to-fertilizer");
+        assert_eq!(parsed.1[1].1, vec![(0, 15, 37), (37, 52, 2), (39, 0, 15),]);
+
+        assert_eq!(parsed.1[2].0, "fertilizer-to-water");
+        assert_eq!(
+            parsed.1[2].1,
+            vec![(49, 53, 8), (0, 11, 42), (42, 0, 7), (57, 7, 4),]
+        );
+
+        assert_eq!(parsed.1[3].0, "water-to-light");
+        assert_eq!(parsed.1[3].1, vec![(88, 18, 7), (18, 25, 70),]);
+
+        assert_eq!(parsed.1[4].0, "light-to-temperature");
+        assert_eq!(
+            parsed.1[4].1,
+            vec![(45, 77, 23), (81, 45, 19), (68, 64, 13),]
+        );
+
+        assert_eq!(parsed.1[5].0, "temperature-to-humidity");
+        assert_eq!(parsed.1[5].1, vec![(0, 69, 1), (1, 0, 69),]);
+
+        assert_eq!(parsed.1[6].0, "humidity-to-location");
+        assert_eq!(parsed.1[6].1, vec![(60, 56, 37), (56, 93, 4),]);
+    }
+
+    #[test]
+    fn write_problem_test() {
+        for name in ["sample.txt", "main.txt"] {
+            let content = read_file(&resource_path!(name).unwrap()).unwrap();
+            let (seeds, maps) = parse_problem::<usize>(&split_lines(&content)).unwrap();
+            assert_eq!(write_problem(&seeds, &maps), content, "{}", name);
+        }
+
+        let maps: Vec<ParsedMap<u32>> = vec![
+            ("seed-to-soil".to_string(), vec![]),
+            ("soil-to-water".to_string(), vec![(u32::MAX - 1, 0, 1)]),
+        ];
+        let almanac = write_problem(&vec![], &maps);
+        assert_eq!(
+            almanac,
+            "seeds:\n\nseed-to-soil map:\n\nsoil-to-water map:\n4294967294 0 1\n"
+        );
+        assert_eq!(parse_problem(&split_lines(&almanac)), Ok((vec![], maps)));
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        fn parse(text: &str) -> Result<(Vec<usize>, Vec<ParsedMap>), ParseError> {
+            parse_problem(&text.split('\n').map(|s| s.to_string()).collect())
+        }
+
+        assert_eq!(
+            parse("seeds: 79 1x4").err(),
+            Some(ParseError::new(1, 11, "a seed number", "\"1x4\""))
+        );
+        assert_eq!(
+            parse("seeds: 79 14 18446744073709551615 2 5").err(),
+            Some(ParseError::new(1, 35, "a seed range within usize", "\"2\""))
+        );
+        assert_eq!(
+            parse("seed 79 14").err(),
+            Some(ParseError::new(1, 1, "\"seeds:\"", "\"seed 79 14\""))
+        );
+        assert_eq!(
+            parse("seeds: 79\n\nseed-to-soil map:\n50 98\n").err(),
+            Some(ParseError::new(4, 1, "3 numbers", "\"50 98\""))
+        );
+        assert_eq!(
+            parse("seeds: 79\n\nseed-to-soil map:\n50 98 -2\n").err(),
+            Some(ParseError::new(4, 7, "a number", "\"-2\""))
+        );
+        assert_eq!(
+            parse("seeds: 79\n\nseed-to-soil map:\n0 18446744073709551615 2\n").err(),
+            Some(ParseError::new(
+                4,
+                1,
+                "a rule within usize",
+                "\"0 18446744073709551615 2\""
+            ))
+        );
+        assert_eq!(
+            parse("seeds: 79\n\n50 98 2\n").err(),
+            Some(ParseError::new(3, 1, "a map title", "\"50 98 2\""))
+        );
+        assert_eq!(
+            parse("seeds: 79\n\nseed-to-soil:\n50 98 2\n").err(),
+            Some(ParseError::new(
+                3,
+                1,
+                "\"<name> map:\"",
+                "\"seed-to-soil:\""
+            ))
+        );
+        assert_eq!(
+            parse("seeds: 79\n\nseedtosoil map:\n50 98 2\n").err(),
+            Some(ParseError::new(
+                3,
+                1,
+                "\"<source>-to-<target>\"",
+                "\"seedtosoil\""
+            ))
+        );
+        assert_eq!(
+            parse("seeds: 79\n\nseed-to-soil map:\n\nwater-to-light map:\n").err(),
+            Some(ParseError::new(
+                5,
+                1,
+                "a map from \"soil\"",
+                "\"water-to-light\""
+            ))
+        );
+        assert_eq!(
+            parse("seeds: 79\n\n  soil-to-water map:\n").err(),
+            Some(ParseError::new(
+                3,
+                3,
+                "a map from \"seed\"",
+                "\"soil-to-water\""
+            ))
+        );
+        assert_eq!(
+            parse("").err(),
+            Some(ParseError::new(1, 1, "\"seeds:\"", "nothing"))
+        );
+    }
+
+    fn set(ranges: &[(usize, usize)]) -> IntervalSet {
+        ranges.iter().map(|(s, e)| Interval::new(*s, *e)).collect()
+    }
+
+    #[test]
+    fn seed_to_range_test() {
+        let seeds: Vec<usize> = vec![79, 14, 55, 13];
+        let ranges = seed_vec_to_ranges(&seeds).unwrap();
+        assert_eq!(ranges.intervals()[0], Interval::new(55, 68));
+        assert_eq!(ranges.intervals()[1], Interval::new(79, 93));
+    }
+
+    #[test]
+    fn single_rule_test() {
+        let transform: ParsedMap = ("single".to_string(), vec![(101, 11, 11)]);
+        let map = |ranges: &[(usize, usize)]| map_ranges(&set(ranges), &transform).unwrap();
+
+        // under
+        assert_eq!(map(&[(1, 11)]), set(&[(1, 11)]));
+
+        // over
+        assert_eq!(map(&[(22, 33)]), set(&[(22, 33)]));
+
+        // equal
+        assert_eq!(map(&[(11, 22)]), set(&[(101, 112)]));
+
+        // within
+        assert_eq!(map(&[(12, 21)]), set(&[(102, 111)]));
+
+        // stradle left
+        assert_eq!(map(&[(8, 19)]), set(&[(8, 11), (101, 109)]));
+
+        // stradle right
+        assert_eq!(map(&[(18, 29)]), set(&[(22, 29), (108, 112)]));
+
+        // surround
+        assert_eq!(map(&[(8, 29)]), set(&[(8, 11), (22, 29), (101, 112)]));
+
+        // from sample
+        let water: ParsedMap = ("fertilizer_to_water".to_string(), vec![(49, 53, 8)]);
+        assert_eq!(
+            map_ranges(&set(&[(55, 68)]), &water).unwrap(),
+            set(&[(51, 57), (61, 68)])
+        );
+    }
+
+    #[test]
+    fn first_rule_wins_test() {
+        // both rules cover 15..20, the first one moves it
+        let overlapping: ParsedMap = (
+            "overlapping".to_string(),
+            vec![(100, 10, 10), (200, 15, 10)],
+        );
+        assert_eq!(
+            map_ranges(&set(&[(5, 30)]), &overlapping).unwrap(),
+            set(&[(5, 10), (25, 30), (100, 110), (205, 210)])
+        );
+    }
+
+    #[test]
+    fn sample2_step_test() {
+        let seeds_in = set(&[(79, 93), (55, 68)]);
+
+        let _seed_to_soil: ParsedMap =
+            ("seed_to_soil".to_string(), vec![(50, 98, 2), (52, 50, 48)]);
+
+        let soil_to_fertilizer: ParsedMap = (
+            "soil_to_fertilizer".to_string(),
+            vec![(0, 15, 37), (37, 52, 2), (39, 0, 15)],
+        );
+        let ranges_got = map_ranges(&seeds_in, &soil_to_fertilizer).unwrap();
+        assert_eq!(ranges_got, set(&[(55, 68), (79, 93)]));
+
+        let fertilizer_to_water: ParsedMap = (
+            "fertilizer_to_water".to_string(),
+            vec![(49, 53, 8), (0, 11, 42), (42, 0, 7), (57, 7, 4)],
+        );
+        let ranges_got = map_ranges(&ranges_got, &fertilizer_to_water).unwrap();
+        assert_eq!(ranges_got, set(&[(51, 57), (61, 68), (79, 93)]));
+
+        let water_to_light: ParsedMap = (
+            "water_to_light".to_string(),
+            vec![(88, 18, 7), (18, 25, 70)], // 25..95 -7
+        );
+        let ranges_got = map_ranges(&ranges_got, &water_to_light).unwrap();
+        assert_eq!(ranges_got, set(&[(44, 50), (54, 61), (72, 86)]));
+
+        let light_to_temperature: ParsedMap = (
+            "light_to_temperature".to_string(),
+            vec![(45, 77, 23), (81, 45, 19), (68, 64, 13)], // 77..100, 45..64, 64..77
+        );
+        let ranges_got = map_ranges(&ranges_got, &light_to_temperature).unwrap();
+        assert_eq!(ranges_got, set(&[(44, 54), (76, 86), (90, 97)]));
+
+        let temperature_to_humidity: ParsedMap = (
+            "temperature_to_humidity".to_string(),
+            vec![(0, 69, 1), (1, 0, 69)], // 69..70, 0..69
+        );
+        let ranges_got = map_ranges(&ranges_got, &temperature_to_humidity).unwrap();
+        assert_eq!(ranges_got, set(&[(45, 55), (76, 86), (90, 97)]));
+
+        let humidity_to_location: ParsedMap = (
+            "humidity_to_location".to_string(),
+            vec![(60, 56, 37), (56, 93, 4)], // 56..93, 93..97
+        );
+        let ranges_got = map_ranges(&ranges_got, &humidity_to_location).unwrap();
+        assert_eq!(ranges_got, set(&[(45, 55), (56, 60), (80, 90), (94, 97)]));
+    }
+
+    #[test]
+    fn range_count_stays_bounded_test() {
+        let lines = get_resource_lines(&resource_path!("main.txt").unwrap());
+        let (seeds, mappings) = parse_problem::<usize>(&lines).unwrap();
+        let seed_ranges = seed_vec_to_ranges(&seeds).unwrap();
+        let locations = chain_map_ranges(&seed_ranges, &mappings).unwrap();
+
+        // every map is a bijection, so no values get lost or duplicated
+        assert_eq!(locations.count(), seed_ranges.count());
+        let rules: usize = mappings.iter().map(|m| m.1.len()).sum();
+        assert!(locations.intervals().len() <= seed_ranges.intervals().len() * (2 * rules + 1));
+    }
+
+    fn parse_lines<T: Unsigned>(text: &str) -> Result<(Vec<T>, Vec<ParsedMap<T>>), ParseError> {
+        parse_problem(&split_lines(text))
+    }
+
+    #[test]
+    fn u32_near_max_test() {
+        // 4294967290 = u32::MAX - 5
+        let text = concat!(
+            "seeds: 4294967290 6 10 3\n",
+            "\n",
+            "seed-to-soil map:\n",
+            "0 4294967292 4\n",
+            "4294967280 10 2\n",
+            "\n",
+            "soil-to-location map:\n",
+            "4294967294 0 2\n",
+        );
+        let (seeds, mappings) = parse_lines::<u32>(text).unwrap();
+        let max = u32::MAX;
+        assert_eq!(
+            chain_mappings(&seeds, mappings.clone()),
+            Ok(vec![max - 5, 6, max - 15, 3])
+        );
+        assert_eq!(
+            chain_mappings(&vec![max - 2, max], mappings.clone()),
+            Ok(vec![max, 3])
+        );
+
+        // the seed range and the first rule end at u32::MAX, and the second
+        // rule moves values up to it
+        let seed_ranges = seed_vec_to_ranges(&seeds).unwrap();
+        let locations = chain_map_ranges(&seed_ranges, &mappings).unwrap();
+        assert_eq!(
+            locations,
+            IntervalSet::from_iter([
+                Interval::new(2, 4),
+                Interval::new(12, 13),
+                Interval::new(max - 15, max - 13),
+                Interval::new(max - 5, max - 3),
+                Interval::inclusive(max - 1, max),
+            ])
+        );
+        assert_eq!(locations.count(), seed_ranges.count());
+        assert_eq!(
+            PiecewiseMap::from_chain(&mappings)
+                .unwrap()
+                .map_set(&seed_ranges),
+            locations
+        );
+    }
+
+    #[test]
+    fn overflow_errors_test() {
+        assert_eq!(
+            parse_lines::<u32>("seeds: 1\n\nseed-to-soil map:\n4294967295 0 1\n"),
+            Ok((
+                vec![1],
+                vec![("seed-to-soil".to_string(), vec![(u32::MAX, 0, 1)])]
+            ))
+        );
+        assert_eq!(
+            parse_lines::<u32>("seeds: 1\n\nseed-to-soil map:\n4294967295 0 2\n").err(),
+            Some(ParseError::new(
+                4,
+                1,
+                "a rule within u32",
+                "\"4294967295 0 2\""
+            ))
+        );
+        assert_eq!(
+            parse_lines::<u32>("seeds: 4294967296\n").err(),
+            Some(ParseError::new(1, 8, "a seed number", "\"4294967296\""))
+        );
+        assert_eq!(
+            seed_vec_to_ranges(&vec![u32::MAX, 2])
+                .unwrap_err()
+                .to_string(),
+            "4294967295 + 2 overflows u32"
+        );
+
+        // rules that didn't go through parse_problem are still checked
+        let rules: Vec<(u32, u32, u32)> = vec![(u32::MAX, 10, 5)];
+        assert_eq!(find_mapping(&rules, 9), Ok(9));
+        assert!(find_mapping(&rules, 11).is_err());
+        let ranges = IntervalSet::from(Interval::new(0, 20));
+        assert!(map_ranges(&ranges, &("overflowing".to_string(), rules)).is_err());
+
+        assert!(Day5::parse("seeds: 18446744073709551615 1\n").is_ok());
+        let err = Day5::parse("seeds: 18446744073709551615 2\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "1:29: expected a seed range within u64, found \"2\""
+        );
+    }
+
+    #[test]
+    fn u128_near_max_test() {
+        let max = u128::MAX;
+        let mappings: Vec<ParsedMap<u128>> = vec![
+            ("seed-to-soil".to_string(), vec![(0, max - 9, 10)]),
+            ("soil-to-location".to_string(), vec![(max - 4, 0, 5)]),
+        ];
+        let composed = PiecewiseMap::from_chain(&mappings).unwrap();
+        assert_eq!(composed.get(max - 9), max - 4);
+        assert_eq!(composed.get(max - 5), max);
+        assert_eq!(composed.get(max - 4), 5);
+        assert_eq!(composed.get(max), 9);
+        assert_eq!(find_preimage(&mappings[1].1, max - 1), Ok(vec![3, max - 1]));
+        assert_eq!(find_preimage(&mappings[1].1, max), Ok(vec![4, max]));
+
+        let seeds = IntervalSet::from(Interval::inclusive(max - 10, max));
+        assert_eq!(
+            chain_map_ranges(&seeds, &mappings).unwrap(),
+            IntervalSet::from_iter([
+                Interval::new(5, 10),
+                Interval::new(max - 10, max - 9),
+                Interval::inclusive(max - 4, max),
+            ])
+        );
+        assert_eq!(
+            composed.map_set(&seeds),
+            chain_map_ranges(&seeds, &mappings).unwrap()
+        );
+        let graph = CategoryGraph::new(mappings).unwrap();
+        assert_eq!(graph.convert("seed", "location", max - 7), Some(max - 2));
+        assert_eq!(graph.convert("seed", "location", max), Some(9));
+    }
+}
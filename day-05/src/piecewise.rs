@@ -0,0 +1,371 @@
+use std::fmt;
+
+use aoc_shared::{End, Interval, IntervalSet, Overflow, Unsigned};
+
+use crate::ParsedMap;
+
+/// Values in `source` are moved to `dst + (value - source.start)`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Segment<T = usize> {
+    pub source: Interval<T>,
+    pub dst: T,
+}
+
+impl<T: Unsigned> Segment<T> {
+    pub fn apply(&self, value: T) -> T {
+        self.dst + (value - self.source.start)
+    }
+
+    pub fn target(&self) -> Interval<T> {
+        self.source.shift(self.source.start, self.dst)
+    }
+}
+
+impl<T: Unsigned> fmt::Display for Segment<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}", self.source, self.target())
+    }
+}
+
+/// A map from `T` to `T` made of sorted, disjoint segments. Values outside
+/// every segment map to themselves, so identity pieces are never stored and
+/// two maps doing the same thing compare equal. Segment targets always fit
+/// in `T`, which the constructors check.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PiecewiseMap<T = usize> {
+    segments: Vec<Segment<T>>,
+}
+
+impl<T: Unsigned> PiecewiseMap<T> {
+    pub fn identity() -> PiecewiseMap<T> {
+        PiecewiseMap { segments: vec![] }
+    }
+
+    /// Builds the map applied by one almanac section, where the first rule
+    /// containing a value wins.
+    pub fn from_map(map: &ParsedMap<T>) -> Result<PiecewiseMap<T>, Overflow> {
+        PiecewiseMap::from_rules(&map.1)
+    }
+
+    pub fn from_rules(rules: &[(T, T, T)]) -> Result<PiecewiseMap<T>, Overflow> {
+        let mut covered = IntervalSet::new();
+        let mut segments = Vec::<Segment<T>>::new();
+        for (dst, src, len) in rules {
+            Interval::try_from_len(*dst, *len)?;
+            let source = IntervalSet::from(Interval::try_from_len(*src, *len)?);
+            for part in source.difference(&covered).iter() {
+                segments.push(Segment {
+                    source: *part,
+                    dst: *dst + (part.start - *src),
+                });
+            }
+            covered = covered.union(&source);
+        }
+        return Ok(PiecewiseMap::from_segments(segments));
+    }
+
+    /// Composes a whole chain of maps, applied in order, into one map.
+    pub fn from_chain(mappings: &Vec<ParsedMap<T>>) -> Result<PiecewiseMap<T>, Overflow> {
+        mappings
+            .iter()
+            .try_fold(PiecewiseMap::identity(), |acc, m| {
+                Ok(acc.then(&PiecewiseMap::from_map(m)?))
+            })
+    }
+
+    /// `segments` must not overlap; identity segments are dropped and
+    /// contiguous segments with the same offset are merged.
+    fn from_segments(mut segments: Vec<Segment<T>>) -> PiecewiseMap<T> {
+        segments.retain(|s| !s.source.is_empty() && s.dst != s.source.start);
+        segments.sort_by_key(|s| s.source.start);
+
+        let mut merged = Vec::<Segment<T>>::with_capacity(segments.len());
+        for segment in segments {
+            match merged.last_mut() {
+                Some(last)
+                    if last.source.end == End::At(segment.source.start)
+                        && last.target().end == End::At(segment.dst) =>
+                {
+                    last.source.end = segment.source.end;
+                }
+                _ => merged.push(segment),
+            }
+        }
+        return PiecewiseMap { segments: merged };
+    }
+
+    pub fn segments(&self) -> &[Segment<T>] {
+        &self.segments
+    }
+
+    pub fn get(&self, value: T) -> T {
+        let index = self
+            .segments
+            .partition_point(|s| s.source.end <= End::At(value));
+        match self.segments.get(index) {
+            Some(segment) if segment.source.contains(value) => segment.apply(value),
+            _ => value,
+        }
+    }
+
+    /// Splits `interval` at the breakpoints of the map; each piece comes with
+    /// the segment moving it, identity gaps included.
+    pub fn pieces(&self, interval: Interval<T>) -> Vec<Segment<T>> {
+        let mut pieces = Vec::<Segment<T>>::new();
+        // `None` once the pieces reach T::MAX
+        let mut cursor = Some(interval.start);
+        let first = self
+            .segments
+            .partition_point(|s| s.source.end <= End::At(interval.start));
+        for segment in &self.segments[first..] {
+            if End::At(segment.source.start) >= interval.end {
+                break;
+            }
+            let hit = segment.source.intersection(&interval).unwrap();
+            if let Some(start) = cursor.filter(|c| *c < hit.start) {
+                pieces.push(Segment {
+                    source: Interval::new(start, hit.start),
+                    dst: start,
+                });
+            }
+            pieces.push(Segment {
+                source: hit,
+                dst: segment.apply(hit.start),
+            });
+            cursor = hit.end.value();
+        }
+        if let Some(start) = cursor.filter(|c| End::At(*c) < interval.end) {
+            pieces.push(Segment {
+                source: Interval {
+                    start,
+                    end: interval.end,
+                },
+                dst: start,
+            });
+        }
+        return pieces;
+    }
+
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.iter()
+            .flat_map(|interval| self.pieces(*interval))
+            .map(|piece| piece.target())
+            .collect()
+    }
+
+    /// Every value that maps into `target`. Since rules may send several
+    /// sources to the same place the result can be larger than `target`;
+    /// values no segment moves are their own preimage.
+    pub fn preimage(&self, target: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut sources = IntervalSet::new();
+        let mut moved = IntervalSet::new();
+        for segment in &self.segments {
+            moved.insert(segment.source);
+            let hits = target.intersection(&IntervalSet::from(segment.target()));
+            for hit in hits.iter() {
+                sources.insert(hit.shift(segment.dst, segment.source.start));
+            }
+        }
+        return sources.union(&target.difference(&moved));
+    }
+
+    /// The map applying `self` first and `next` to the result.
+    pub fn then(&self, next: &PiecewiseMap<T>) -> PiecewiseMap<T> {
+        let mut segments = Vec::<Segment<T>>::new();
+        for piece in self.pieces(Interval::inclusive(T::ZERO, T::MAX)) {
+            let image = piece.target();
+            for part in next.pieces(image) {
+                segments.push(Segment {
+                    source: part.source.shift(image.start, piece.source.start),
+                    dst: part.dst,
+                });
+            }
+        }
+        return PiecewiseMap::from_segments(segments);
+    }
+}
+
+impl<T: Unsigned> fmt::Display for PiecewiseMap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            writeln!(f, "{}", segment)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::{get_resource_lines, resource_path};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{chain_map_ranges, chain_mappings, parse_problem, seed_vec_to_ranges};
+
+    fn segment(start: usize, end: usize, dst: usize) -> Segment {
+        Segment {
+            source: Interval::new(start, end),
+            dst,
+        }
+    }
+
+    fn load(name: &str) -> (Vec<usize>, Vec<ParsedMap>) {
+        parse_problem(&get_resource_lines(&resource_path!(name).unwrap())).unwrap()
+    }
+
+    #[test]
+    fn from_map_test() {
+        let map: ParsedMap = (
+            "test".to_string(),
+            vec![(50, 98, 2), (52, 50, 48), (7, 7, 3), (0, 40, 20)],
+        );
+        // identity rule dropped, second rule only keeps the part the first
+        // one doesn't cover
+        assert_eq!(
+            PiecewiseMap::from_map(&map).unwrap().segments(),
+            &[
+                segment(40, 50, 0),
+                segment(50, 98, 52),
+                segment(98, 100, 50)
+            ]
+        );
+    }
+
+    #[test]
+    fn get_test() {
+        let map =
+            PiecewiseMap::from_map(&("test".to_string(), vec![(50, 98, 2), (52, 50, 48)])).unwrap();
+        assert_eq!(map.get(0), 0);
+        assert_eq!(map.get(49), 49);
+        assert_eq!(map.get(50), 52);
+        assert_eq!(map.get(97), 99);
+        assert_eq!(map.get(98), 50);
+        assert_eq!(map.get(99), 51);
+        assert_eq!(map.get(100), 100);
+        assert_eq!(map.get(usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn then_test() {
+        let shift = PiecewiseMap::from_map(&("shift".to_string(), vec![(10, 0, 10)])).unwrap();
+        let back = PiecewiseMap::from_map(&("back".to_string(), vec![(0, 10, 10)])).unwrap();
+        assert_eq!(
+            shift.then(&back).segments(),
+            &[segment(10, 20, 0)],
+            "0..10 goes to 10..20 and back, 10..20 goes to 0..10"
+        );
+        assert_eq!(shift.then(&PiecewiseMap::identity()), shift);
+        assert_eq!(PiecewiseMap::identity().then(&back), back);
+    }
+
+    #[test]
+    fn merges_contiguous_segments_test() {
+        let split =
+            PiecewiseMap::from_map(&("split".to_string(), vec![(100, 0, 5), (105, 5, 5)])).unwrap();
+        assert_eq!(split.segments(), &[segment(0, 10, 100)]);
+        assert_eq!(split.to_string(), "0..10 -> 100..110\n");
+    }
+
+    #[test]
+    fn pieces_test() {
+        let map =
+            PiecewiseMap::from_map(&("test".to_string(), vec![(100, 10, 5), (0, 20, 5)])).unwrap();
+        assert_eq!(
+            map.pieces(Interval::new(5, 30)),
+            vec![
+                segment(5, 10, 5),
+                segment(10, 15, 100),
+                segment(15, 20, 15),
+                segment(20, 25, 0),
+                segment(25, 30, 25),
+            ]
+        );
+        assert_eq!(
+            map.pieces(Interval::new(12, 13)),
+            vec![segment(12, 13, 102)]
+        );
+    }
+
+    #[test]
+    fn preimage_test() {
+        let map = PiecewiseMap::<usize>::from_rules(&[(50, 98, 2), (52, 50, 48)]).unwrap();
+        let preimage = |s, e| map.preimage(&IntervalSet::from(Interval::new(s, e)));
+
+        assert_eq!(preimage(10, 11).intervals(), &[Interval::new(10, 11)]);
+        assert_eq!(preimage(50, 51).intervals(), &[Interval::new(98, 99)]);
+        assert_eq!(preimage(99, 100).intervals(), &[Interval::new(97, 98)]);
+        assert_eq!(
+            preimage(40, 110).intervals(),
+            &[Interval::new(40, 110)],
+            "the map is a bijection on 50..100"
+        );
+
+        // 0..5 is reached from 10..15 and from itself
+        let squash = PiecewiseMap::<usize>::from_rules(&[(0, 10, 5)]).unwrap();
+        assert_eq!(
+            squash.preimage(&IntervalSet::from(Interval::new(2, 4))),
+            IntervalSet::from_iter([Interval::new(2, 4), Interval::new(12, 14)])
+        );
+        // 10..15 is moved away and nothing lands on it
+        assert_eq!(
+            squash.preimage(&IntervalSet::from(Interval::new(10, 15))),
+            IntervalSet::new()
+        );
+    }
+
+    fn check_against_steps(name: &str) {
+        let (seeds, mappings) = load(name);
+        let composed = PiecewiseMap::from_chain(&mappings).unwrap();
+
+        assert_eq!(
+            seeds
+                .iter()
+                .map(|s| composed.get(*s))
+                .collect::<Vec<usize>>(),
+            chain_mappings(&seeds, mappings.clone()).unwrap(),
+            "{}: seeds",
+            name
+        );
+
+        let seed_ranges = seed_vec_to_ranges(&seeds).unwrap();
+        assert_eq!(
+            composed.map_set(&seed_ranges),
+            chain_map_ranges(&seed_ranges, &mappings).unwrap(),
+            "{}: seed ranges",
+            name
+        );
+
+        // every breakpoint of every step, with its neighbours
+        let mut probes = vec![0, 1, usize::MAX - 1];
+        for (_, rules) in &mappings {
+            for (dst, src, len) in rules {
+                for value in [*dst, *src, src + len] {
+                    probes.extend([value.saturating_sub(1), value, value + 1]);
+                }
+            }
+        }
+        for value in probes {
+            let step_by_step = mappings
+                .iter()
+                .fold(value, |acc, m| PiecewiseMap::from_map(m).unwrap().get(acc));
+            assert_eq!(composed.get(value), step_by_step, "{}: {}", name, value);
+        }
+    }
+
+    #[test]
+    fn compose_sample_test() {
+        check_against_steps("sample.txt");
+
+        let (_, mappings) = load("sample.txt");
+        let composed = PiecewiseMap::from_chain(&mappings).unwrap();
+        assert_eq!(composed.get(79), 82);
+        assert_eq!(composed.get(14), 43);
+        assert_eq!(composed.get(55), 86);
+        assert_eq!(composed.get(13), 35);
+    }
+
+    #[test]
+    fn compose_main_test() {
+        check_against_steps("main.txt");
+    }
+}
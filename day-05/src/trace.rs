@@ -0,0 +1,180 @@
+use std::fmt::Write;
+
+use aoc_shared::{Interval, Unsigned};
+use serde::{Deserialize, Serialize};
+
+/// One rule applied to one piece of an input range: `middle` is the part the
+/// rule covers and moves to `mapped`, `left` and `right` are what's left for
+/// the following rules.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Split<T = usize> {
+    pub rule: usize,
+    pub piece: Interval<T>,
+    pub left: Option<Interval<T>>,
+    pub middle: Interval<T>,
+    pub right: Option<Interval<T>>,
+    pub mapped: Interval<T>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct RangeTrace<T = usize> {
+    pub input: Interval<T>,
+    pub splits: Vec<Split<T>>,
+    /// Pieces no rule covered, kept as they are.
+    pub unmatched: Vec<Interval<T>>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct StageTrace<T = usize> {
+    pub map: String,
+    pub ranges: Vec<RangeTrace<T>>,
+    pub output: Vec<Interval<T>>,
+}
+
+impl<T> StageTrace<T> {
+    pub fn new(map: &str) -> StageTrace<T> {
+        StageTrace {
+            map: map.to_string(),
+            ranges: vec![],
+            output: vec![],
+        }
+    }
+}
+
+/// Everything `chain_map_ranges_traced` did, stage by stage.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Trace<T = usize> {
+    pub stages: Vec<StageTrace<T>>,
+}
+
+impl<T> Default for Trace<T> {
+    fn default() -> Trace<T> {
+        Trace { stages: vec![] }
+    }
+}
+
+impl<T: Unsigned + Serialize> Trace<T> {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn render(&self) -> String {
+        let optional = |i: &Option<Interval<T>>| match i {
+            Some(i) => i.to_string(),
+            None => "-".to_string(),
+        };
+        let list = |intervals: &[Interval<T>]| {
+            intervals
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        let mut out = String::new();
+        for stage in &self.stages {
+            writeln!(out, "{}", stage.map).unwrap();
+            for range in &stage.ranges {
+                writeln!(out, "  {}", range.input).unwrap();
+                for split in &range.splits {
+                    writeln!(
+                        out,
+                        "    rule {} splits {} into {} | {} | {}, {} -> {}",
+                        split.rule + 1,
+                        split.piece,
+                        optional(&split.left),
+                        split.middle,
+                        optional(&split.right),
+                        split.middle,
+                        split.mapped
+                    )
+                    .unwrap();
+                }
+                if !range.unmatched.is_empty() {
+                    writeln!(out, "    unmatched: {}", list(&range.unmatched)).unwrap();
+                }
+            }
+            writeln!(out, "  = {}", list(&stage.output)).unwrap();
+        }
+        return out;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::{get_resource_lines, resource_path, IntervalSet};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        chain_map_ranges, chain_map_ranges_traced, parse_problem, seed_vec_to_ranges, ParsedMap,
+    };
+
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        let lines = get_resource_lines(&resource_path!("sample.txt").unwrap());
+        let (_, mappings) = parse_problem::<usize>(&lines).unwrap();
+        let seeds = IntervalSet::from(Interval::new(79, 93));
+        let (_, trace) = chain_map_ranges_traced(&seeds, &mappings[..3].to_vec()).unwrap();
+
+        assert_eq!(
+            trace.render(),
+            concat!(
+                "seed-to-soil\n",
+                "  79..93\n",
+                "    rule 2 splits 79..93 into - | 79..93 | -, 79..93 -> 81..95\n",
+                "  = 81..95\n",
+                "soil-to-fertilizer\n",
+                "  81..95\n",
+                "    unmatched: 81..95\n",
+                "  = 81..95\n",
+                "fertilizer-to-water\n",
+                "  81..95\n",
+                "    unmatched: 81..95\n",
+                "  = 81..95\n",
+            )
+        );
+    }
+
+    #[test]
+    fn split_test() {
+        let mapping: ParsedMap = ("single".to_string(), vec![(0, 20, 5), (101, 11, 11)]);
+        let seeds = IntervalSet::from(Interval::new(8, 29));
+        let (_, trace) = chain_map_ranges_traced(&seeds, &vec![mapping]).unwrap();
+
+        assert_eq!(
+            trace.render(),
+            concat!(
+                "single\n",
+                "  8..29\n",
+                "    rule 1 splits 8..29 into 8..20 | 20..25 | 25..29, 20..25 -> 0..5\n",
+                "    rule 2 splits 8..20 into 8..11 | 11..20 | -, 11..20 -> 101..110\n",
+                "    unmatched: 8..11, 25..29\n",
+                "  = 0..5, 8..11, 25..29, 101..110\n",
+            )
+        );
+    }
+
+    #[test]
+    fn traced_matches_untraced_test() {
+        let lines = get_resource_lines(&resource_path!("main.txt").unwrap());
+        let (seeds, mappings) = parse_problem::<usize>(&lines).unwrap();
+        let seed_ranges = seed_vec_to_ranges(&seeds).unwrap();
+        let (locations, trace) = chain_map_ranges_traced(&seed_ranges, &mappings).unwrap();
+
+        assert_eq!(
+            locations,
+            chain_map_ranges(&seed_ranges, &mappings).unwrap()
+        );
+        assert_eq!(trace.stages.len(), 7);
+        for (stage, next) in trace.stages.iter().zip(trace.stages.iter().skip(1)) {
+            let inputs: Vec<Interval> = next.ranges.iter().map(|r| r.input).collect();
+            assert_eq!(stage.output, inputs, "{} -> {}", stage.map, next.map);
+        }
+        assert_eq!(trace.stages[6].output, locations.intervals());
+
+        let round_trip: Trace = serde_json::from_str(&trace.to_json()).unwrap();
+        assert_eq!(round_trip, trace);
+    }
+}
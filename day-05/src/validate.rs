@@ -0,0 +1,204 @@
+use std::fmt;
+
+use aoc_shared::{Interval, Unsigned};
+
+use crate::ParsedMap;
+
+/// Something suspicious about the rules of one map. Rule numbers are indexes
+/// into the map's rules.
+#[derive(Debug, PartialEq, Clone)]
+pub enum MapIssue<T = usize> {
+    ZeroLength {
+        rule: usize,
+    },
+    /// The last value of the rule's source or destination is past `T::MAX`.
+    Overflow {
+        rule: usize,
+    },
+    Overlap {
+        rule: usize,
+        other: usize,
+        values: Interval<T>,
+    },
+}
+
+impl<T: Unsigned> fmt::Display for MapIssue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapIssue::ZeroLength { rule } => write!(f, "rule {} has length 0", rule + 1),
+            MapIssue::Overflow { rule } => {
+                write!(f, "rule {} runs past {}::MAX", rule + 1, T::NAME)
+            }
+            MapIssue::Overlap {
+                rule,
+                other,
+                values,
+            } => write!(
+                f,
+                "rules {} and {} both cover {}, rule {} wins",
+                other + 1,
+                rule + 1,
+                values,
+                other + 1
+            ),
+        }
+    }
+}
+
+/// Source interval of a rule, `None` if the last value of its source or
+/// destination, `src + len - 1` or `dst + len - 1`, doesn't fit in `T`.
+pub fn rule_source<T: Unsigned>((dst, src, len): (T, T, T)) -> Option<Interval<T>> {
+    Interval::try_from_len(dst, len).ok()?;
+    Interval::try_from_len(src, len).ok()
+}
+
+pub fn validate_map<T: Unsigned>(map: &ParsedMap<T>) -> Vec<MapIssue<T>> {
+    let mut issues = Vec::<MapIssue<T>>::new();
+    let mut sources = Vec::<(usize, Interval<T>)>::new();
+    for (rule, transform) in map.1.iter().enumerate() {
+        if transform.2 == T::ZERO {
+            issues.push(MapIssue::ZeroLength { rule });
+            continue;
+        }
+        match rule_source(*transform) {
+            Some(source) => sources.push((rule, source)),
+            None => issues.push(MapIssue::Overflow { rule }),
+        }
+    }
+
+    for (i, (rule, source)) in sources.iter().enumerate() {
+        for (other, other_source) in &sources[..i] {
+            if let Some(values) = source.intersection(other_source) {
+                issues.push(MapIssue::Overlap {
+                    rule: *rule,
+                    other: *other,
+                    values,
+                });
+            }
+        }
+    }
+    return issues;
+}
+
+/// Issues of every map, with the title of the map they were found in.
+pub fn validate_almanac<T: Unsigned>(maps: &[ParsedMap<T>]) -> Vec<(String, MapIssue<T>)> {
+    maps.iter()
+        .flat_map(|map| {
+            validate_map(map)
+                .into_iter()
+                .map(|issue| (map.0.clone(), issue))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::{get_resource_lines, resource_path};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::parse_problem;
+
+    fn map(rules: Vec<(usize, usize, usize)>) -> ParsedMap {
+        ("seed-to-soil".to_string(), rules)
+    }
+
+    #[test]
+    fn valid_inputs_test() {
+        for name in ["sample.txt", "main.txt"] {
+            let lines = get_resource_lines(&resource_path!(name).unwrap());
+            let (_, maps) = parse_problem::<usize>(&lines).unwrap();
+            assert_eq!(validate_almanac(&maps), vec![], "{}", name);
+        }
+    }
+
+    #[test]
+    fn adjacent_rules_test() {
+        // 10..14 and 14..16 touch but don't overlap
+        assert_eq!(validate_map(&map(vec![(0, 10, 4), (50, 14, 2)])), vec![]);
+    }
+
+    #[test]
+    fn overlap_test() {
+        let issues = validate_map(&map(vec![(0, 10, 5), (50, 20, 5), (100, 14, 7)]));
+        assert_eq!(
+            issues,
+            vec![
+                MapIssue::Overlap {
+                    rule: 2,
+                    other: 0,
+                    values: Interval::new(14, 15)
+                },
+                MapIssue::Overlap {
+                    rule: 2,
+                    other: 1,
+                    values: Interval::new(20, 21)
+                },
+            ]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "rules 1 and 3 both cover 14..15, rule 1 wins"
+        );
+    }
+
+    #[test]
+    fn zero_length_test() {
+        let issues = validate_map(&map(vec![(0, 10, 0), (0, 10, 5)]));
+        assert_eq!(issues, vec![MapIssue::ZeroLength { rule: 0 }]);
+        assert_eq!(issues[0].to_string(), "rule 1 has length 0");
+    }
+
+    #[test]
+    fn rule_ending_at_max_test() {
+        // the last value of the source or the destination is usize::MAX
+        let max = usize::MAX;
+        assert_eq!(validate_map(&map(vec![(0, max, 1)])), vec![]);
+        assert_eq!(validate_map(&map(vec![(max, 0, 1)])), vec![]);
+        assert_eq!(validate_map(&map(vec![(max - 4, max - 9, 5)])), vec![]);
+        assert_eq!(
+            rule_source((0, max - 2, 3)),
+            Some(Interval::inclusive(max - 2, max))
+        );
+        assert_eq!(rule_source((max - 1, 0, 3)), None);
+
+        let issues = validate_map(&map(vec![(0, max - 2, 3), (10, max, 1)]));
+        assert_eq!(
+            issues,
+            vec![MapIssue::Overlap {
+                rule: 1,
+                other: 0,
+                values: Interval::inclusive(max, max)
+            }]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "rules 1 and 2 both cover 18446744073709551615..=18446744073709551615, rule 1 wins"
+        );
+    }
+
+    #[test]
+    fn overflow_test() {
+        let max = usize::MAX;
+        assert_eq!(validate_map(&map(vec![(0, max - 2, 2)])), vec![]);
+        assert_eq!(
+            validate_map(&map(vec![(0, max - 3, 4), (max - 1, 0, 3), (max, 10, 2)])),
+            vec![
+                MapIssue::Overflow { rule: 1 },
+                MapIssue::Overflow { rule: 2 }
+            ]
+        );
+        assert_eq!(
+            validate_map(&map(vec![(0, max, 2)])),
+            vec![MapIssue::Overflow { rule: 0 }]
+        );
+        assert_eq!(
+            MapIssue::<usize>::Overflow { rule: 0 }.to_string(),
+            "rule 1 runs past usize::MAX"
+        );
+        assert_eq!(
+            MapIssue::<u32>::Overflow { rule: 0 }.to_string(),
+            "rule 1 runs past u32::MAX"
+        );
+    }
+}
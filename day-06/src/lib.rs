@@ -0,0 +1,236 @@
+// predates the workspace, kept in its original style
+#![allow(clippy::needless_return, clippy::ptr_arg, clippy::useless_conversion)]
+
+use aoc_shared::{next_token, parse_at, split_lines, AocError, ParseError, Solution};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Race {
+    time: i64,
+    distance: i64,
+}
+
+impl Race {
+    pub fn new(time: i64, distance: i64) -> Race {
+        Race { time, distance }
+    }
+}
+
+// values following the `<title>:` label of line `index`
+fn parse_labeled_values<'a>(
+    lines: &'a Vec<String>,
+    index: usize,
+    title: &str,
+) -> Result<(&'a str, Vec<&'a str>), ParseError> {
+    let expected = format!("\"{}:\"", title);
+    let line = match lines.get(index) {
+        Some(line) => line,
+        None => return Err(ParseError::new(index + 1, 1, &expected, "end of input")),
+    };
+    let mut split = line.split_whitespace();
+    let label = next_token(&mut split, index + 1, line, &expected)?;
+    if label != format!("{}:", title) {
+        return Err(ParseError::at(index + 1, line, label, &expected));
+    }
+    let values: Vec<&str> = split.collect();
+    if values.is_empty() {
+        return Err(ParseError::end_of_line(index + 1, line, "a number"));
+    }
+    return Ok((line, values));
+}
+
+pub fn parse_problem1(lines: &Vec<String>) -> Result<Vec<Race>, ParseError> {
+    let (time_line, time_strs) = parse_labeled_values(lines, 0, "Time")?;
+    let times: Vec<i64> = time_strs
+        .iter()
+        .map(|v| parse_at(1, time_line, v, "a number"))
+        .collect::<Result<_, _>>()?;
+
+    let (distance_line, distance_strs) = parse_labeled_values(lines, 1, "Distance")?;
+    let distances: Vec<i64> = distance_strs
+        .iter()
+        .map(|v| parse_at(2, distance_line, v, "a number"))
+        .collect::<Result<_, _>>()?;
+
+    if distances.len() < times.len() {
+        return Err(ParseError::end_of_line(
+            2,
+            distance_line,
+            &format!("{} distances", times.len()),
+        ));
+    }
+    if distances.len() > times.len() {
+        let surplus = distance_strs[times.len()];
+        return Err(ParseError::at(2, distance_line, surplus, "end of line"));
+    }
+
+    return Ok(times
+        .into_iter()
+        .zip(distances.into_iter())
+        .map(|(t, d)| Race {
+            time: t,
+            distance: d,
+        })
+        .collect());
+}
+
+pub fn parse_problem2(lines: &Vec<String>) -> Result<Race, ParseError> {
+    fn joined(index: usize, line: &str, values: Vec<&str>) -> Result<i64, ParseError> {
+        for v in values.iter() {
+            if !v.chars().all(|c| c.is_ascii_digit()) {
+                return Err(ParseError::at(index + 1, line, v, "a number"));
+            }
+        }
+        return values
+            .concat()
+            .parse::<i64>()
+            .map_err(|_| ParseError::at(index + 1, line, values[0], "a number that fits in i64"));
+    }
+
+    let (time_line, time_strs) = parse_labeled_values(lines, 0, "Time")?;
+    let (distance_line, distance_strs) = parse_labeled_values(lines, 1, "Distance")?;
+    return Ok(Race::new(
+        joined(0, time_line, time_strs)?,
+        joined(1, distance_line, distance_strs)?,
+    ));
+}
+
+pub fn num_ways_to_win(race: Race) -> i64 {
+    fn distance(press: i64, total: i64) -> i64 {
+        if press <= 0 || press >= total {
+            return 0;
+        }
+        return (press) * (total - press);
+    }
+
+    let mut count = 0;
+    for press in 1..race.time {
+        if distance(press, race.time) > race.distance {
+            count += 1;
+        }
+    }
+    return count;
+}
+
+pub fn main_input() -> Option<String> {
+    aoc_shared::resource_path!("main.txt")
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    // the part 2 race is kept even if it fails to parse, joining the numbers
+    // can overflow where part 1 reads them fine
+    type Input = (Vec<Race>, Result<Race, ParseError>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let lines = split_lines(input);
+        Ok((parse_problem1(&lines)?, parse_problem2(&lines)))
+    }
+
+    fn part1((races, _): &Self::Input) -> Self::Part1 {
+        races.iter().cloned().map(num_ways_to_win).product()
+    }
+
+    fn part2((_, race): &Self::Input) -> Self::Part2 {
+        match race {
+            Ok(race) => num_ways_to_win(race.clone()),
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::aoc_test;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    aoc_shared::answers_test!(Day6);
+
+    aoc_test!(
+        parse1_test,
+        "sample.txt",
+        |lines| parse_problem1(lines).unwrap(),
+        vec![Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)]
+    );
+
+    #[test]
+    fn parse_errors_test() {
+        fn lines(text: &str) -> Vec<String> {
+            text.split('\n').map(|s| s.to_string()).collect()
+        }
+
+        let input = lines("Time: 7 15\nDistance: 9 4o");
+        assert_eq!(
+            parse_problem1(&input).err(),
+            Some(ParseError::new(2, 13, "a number", "\"4o\""))
+        );
+        assert_eq!(
+            parse_problem2(&input).err(),
+            Some(ParseError::new(2, 13, "a number", "\"4o\""))
+        );
+
+        let input = lines("Time: 7 15\nDistance: 9");
+        assert_eq!(
+            parse_problem1(&input).err(),
+            Some(ParseError::new(2, 12, "2 distances", "end of line"))
+        );
+
+        let input = lines("Time: 7 15\nDistance: 9 40 200 3");
+        assert_eq!(
+            parse_problem1(&input).err(),
+            Some(ParseError::new(2, 16, "end of line", "\"200\""))
+        );
+
+        let input = lines("Time: 7 15");
+        assert_eq!(
+            parse_problem1(&input).err(),
+            Some(ParseError::new(2, 1, "\"Distance:\"", "end of input"))
+        );
+
+        let input = lines("Tiem: 7 15\nDistance: 9 40");
+        assert_eq!(
+            parse_problem2(&input).err(),
+            Some(ParseError::new(1, 1, "\"Time:\"", "\"Tiem:\""))
+        );
+
+        let input = lines("Time:\nDistance: 9 40");
+        assert_eq!(
+            parse_problem2(&input).err(),
+            Some(ParseError::new(1, 6, "a number", "end of line"))
+        );
+    }
+
+    #[test]
+    fn part2_only_error_test() {
+        // each distance fits in i64, the two of them written together don't
+        let input = Day6::parse("Time: 7 15\nDistance: 9999999999 9999999999\n").unwrap();
+        assert_eq!(Day6::part1(&input), 0);
+        assert_eq!(
+            input.1,
+            Err(ParseError::new(
+                2,
+                11,
+                "a number that fits in i64",
+                "\"9999999999\""
+            ))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a number that fits in i64, found \"9999999999\"")]
+    fn part2_overflow_test() {
+        let input = Day6::parse("Time: 7 15\nDistance: 9999999999 9999999999\n").unwrap();
+        Day6::part2(&input);
+    }
+
+    aoc_test!(
+        parse2_test,
+        "sample.txt",
+        |lines| parse_problem2(lines).unwrap(),
+        Race::new(71530, 940200)
+    );
+}
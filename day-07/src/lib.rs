@@ -1,8 +1,16 @@
+// predates the workspace, kept in its original style
+#![allow(
+    clippy::clone_on_copy,
+    clippy::into_iter_on_ref,
+    clippy::needless_borrow,
+    clippy::needless_return,
+    clippy::non_canonical_partial_ord_impl,
+    clippy::ptr_arg
+)]
+
 use std::collections::HashMap;
 
-fn main() {
-    println!("Hello, world!");
-}
+use aoc_shared::{next_token, parse_at, split_lines, AocError, ParseError, Solution};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum HandKind {
@@ -63,7 +71,8 @@ impl PartialOrd for Hand {
     }
 }
 
-pub fn parse_hand(line: &str, use_jokers: bool) -> Hand {
+// errors are reported on line 1, `parse_problem` fixes up the line number
+pub fn parse_hand(line: &str, use_jokers: bool) -> Result<Hand, ParseError> {
     let char_map: HashMap<char, i32> = [
         ('1', 1),
         ('2', 2),
@@ -90,23 +99,38 @@ pub fn parse_hand(line: &str, use_jokers: bool) -> Hand {
     .collect();
 
     let mut split = line.split_whitespace();
-    let hand: [i32; 5] = split
-        .next()
-        .unwrap()
-        .chars()
-        .map(|c| char_map.get(&c).unwrap().clone())
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap();
-
-    let bet = split.next().unwrap_or("").parse::<i32>().unwrap_or(0);
-    Hand::new(hand, bet, get_hand_kind(&hand))
+    let cards_str = next_token(&mut split, 1, line, "5 cards")?;
+    let cards: Vec<i32> = cards_str
+        .char_indices()
+        .map(|(i, c)| match char_map.get(&c) {
+            Some(card) => Ok(card.clone()),
+            None => {
+                let card_str = &cards_str[i..i + c.len_utf8()];
+                Err(ParseError::at(1, line, card_str, "a card"))
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    let hand: [i32; 5] = match cards.try_into() {
+        Ok(hand) => hand,
+        Err(_) => return Err(ParseError::at(1, line, cards_str, "5 cards")),
+    };
+
+    // the bet is optional, hands without one are worth nothing
+    let bet = match split.next() {
+        Some(bet_str) => parse_at(1, line, bet_str, "a bet")?,
+        None => 0,
+    };
+    if let Some(extra) = split.next() {
+        return Err(ParseError::at(1, line, extra, "end of line"));
+    }
+    Ok(Hand::new(hand, bet, get_hand_kind(&hand)))
 }
 
-pub fn parse_problem(lines: &Vec<String>, use_jokers: bool) -> Vec<Hand> {
+pub fn parse_problem(lines: &Vec<String>, use_jokers: bool) -> Result<Vec<Hand>, ParseError> {
     lines
         .into_iter()
-        .map(|l| parse_hand(l, use_jokers))
+        .enumerate()
+        .map(|(index, l)| parse_hand(l, use_jokers).map_err(|e| e.with_line(index + 1)))
         .collect()
 }
 
@@ -193,183 +217,193 @@ pub fn get_hand_kind(cards: &[i32; 5]) -> HandKind {
     }
 }
 
+pub fn total_winnings(hands: &Vec<Hand>) -> i32 {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
+    hands.sort();
+    hands
+        .iter()
+        .enumerate()
+        .fold(0i32, |acc, (idx, h)| acc + ((idx as i32 + 1) * h.bet))
+}
+
+pub fn main_input() -> Option<String> {
+    aoc_shared::resource_path!("main.txt")
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    // (hands without jokers, hands with jokers)
+    type Input = (Vec<Hand>, Vec<Hand>);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let lines = split_lines(input);
+        Ok((parse_problem(&lines, false)?, parse_problem(&lines, true)?))
+    }
+
+    fn part1((hands, _): &Self::Input) -> Self::Part1 {
+        total_winnings(hands)
+    }
+
+    fn part2((_, hands): &Self::Input) -> Self::Part2 {
+        total_winnings(hands)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use aoc_shared::{get_resource_lines, resource_path};
+    use aoc_shared::aoc_test;
     use pretty_assertions::assert_eq;
 
     use super::*;
 
-    #[test]
-    fn parse1_test() {
-        let lines = get_resource_lines(&resource_path!("sample.txt").unwrap());
-        let parsed = parse_problem(&lines, false);
-        let exp = vec![
+    aoc_shared::answers_test!(Day7);
+
+    aoc_test!(
+        parse1_test,
+        "sample.txt",
+        |lines| parse_problem(lines, false).unwrap(),
+        vec![
             Hand::new([3, 2, 10, 3, 13], 765, HandKind::OnePair),
             Hand::new([10, 5, 5, 11, 5], 684, HandKind::ThreeKind),
             Hand::new([13, 13, 6, 7, 7], 28, HandKind::TwoPair),
             Hand::new([13, 10, 11, 11, 10], 220, HandKind::TwoPair),
             Hand::new([12, 12, 12, 11, 14], 483, HandKind::ThreeKind),
-        ];
-        assert_eq!(parsed, exp);
+        ]
+    );
+
+    #[test]
+    fn parse_errors_test() {
+        assert_eq!(
+            parse_hand("12X45 10", false).err(),
+            Some(ParseError::new(1, 3, "a card", "\"X\""))
+        );
+        assert_eq!(
+            parse_hand("1234 10", false).err(),
+            Some(ParseError::new(1, 1, "5 cards", "\"1234\""))
+        );
+        assert_eq!(
+            parse_hand("12345 1O", false).err(),
+            Some(ParseError::new(1, 7, "a bet", "\"1O\""))
+        );
+        assert_eq!(
+            parse_hand("12345 10 3", false).err(),
+            Some(ParseError::new(1, 10, "end of line", "\"3\""))
+        );
+        assert_eq!(
+            parse_hand("", false).err(),
+            Some(ParseError::new(1, 1, "5 cards", "end of line"))
+        );
+
+        let lines = vec!["32T3K 765".to_string(), "T55J5 6B4".to_string()];
+        assert_eq!(
+            parse_problem(&lines, true).err(),
+            Some(ParseError::new(2, 7, "a bet", "\"6B4\""))
+        );
     }
 
     #[test]
     fn hand_kind_test() {
-        let hand = parse_hand("12345", false);
+        let hand = parse_hand("12345", false).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::HighCard);
 
-        let hand = parse_hand("12315", false);
+        let hand = parse_hand("12315", false).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::OnePair);
 
-        let hand = parse_hand("12312", false);
+        let hand = parse_hand("12312", false).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::TwoPair);
 
-        let hand = parse_hand("12115", false);
+        let hand = parse_hand("12115", false).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::ThreeKind);
 
-        let hand = parse_hand("12121", false);
+        let hand = parse_hand("12121", false).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::FullHouse);
 
-        let hand = parse_hand("18888", false);
+        let hand = parse_hand("18888", false).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::FourKind);
 
-        let hand = parse_hand("99999", false);
+        let hand = parse_hand("99999", false).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::FiveKind);
     }
 
     #[test]
     fn hand_ordering_test() {
-        let small = parse_hand("12345", false);
-        let big = parse_hand("11345", false);
+        let small = parse_hand("12345", false).unwrap();
+        let big = parse_hand("11345", false).unwrap();
         #[rustfmt::skip] let str = if small < big { "".to_string() } else { format!("{:?} !< {:?}", small, big) };
         assert_eq!(str, "");
         #[rustfmt::skip] let str = if big > small { "".to_string() } else { format!("{:?} !< {:?}", small, big) };
         assert_eq!(str, "");
 
-        let small = parse_hand("12111", false);
-        let big = parse_hand("13111", false);
+        let small = parse_hand("12111", false).unwrap();
+        let big = parse_hand("13111", false).unwrap();
         #[rustfmt::skip] let str = if small < big { "".to_string() } else { format!("{:?} !< {:?}", small, big) };
         assert_eq!(str, "");
         #[rustfmt::skip] let str = if big > small { "".to_string() } else { format!("{:?} !< {:?}", small, big) };
         assert_eq!(str, "");
 
-        let small = parse_hand("11221", false);
-        let big = parse_hand("11221", false);
+        let small = parse_hand("11221", false).unwrap();
+        let big = parse_hand("11221", false).unwrap();
         #[rustfmt::skip] let str = if small == big { "".to_string() } else { format!("{:?} == {:?}", small, big) };
         assert_eq!(str, "");
         #[rustfmt::skip] let str = if big == small { "".to_string() } else { format!("{:?} == {:?}", small, big) };
         assert_eq!(str, "");
     }
 
-    #[test]
-    fn part1_sample_test() {
-        let lines = get_resource_lines(&resource_path!("sample.txt").unwrap());
-        let mut hands = parse_problem(&lines, false);
-        hands.sort();
-
-        let value = hands
-            .iter()
-            .enumerate()
-            .fold(0i32, |acc, (idx, h)| acc + ((idx as i32 + 1) * h.bet));
-
-        assert_eq!(value, 6440);
-    }
-
-    #[test]
-    fn part1_main_test() {
-        let lines = get_resource_lines(&resource_path!("main.txt").unwrap());
-        let mut hands = parse_problem(&lines, false);
-        hands.sort();
-
-        let value = hands
-            .iter()
-            .enumerate()
-            .fold(0i32, |acc, (idx, h)| acc + ((idx as i32 + 1) * h.bet));
-
-        assert_eq!(value, 248217452);
-    }
-
     #[test]
     fn hand_kind_joker_test() {
-        let hand = parse_hand("1234J", true);
+        let hand = parse_hand("1234J", true).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::OnePair);
 
-        let hand = parse_hand("123JJ", true);
+        let hand = parse_hand("123JJ", true).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::ThreeKind);
 
-        let hand = parse_hand("122JJ", true);
+        let hand = parse_hand("122JJ", true).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::FourKind);
 
-        let hand = parse_hand("J22JJ", true);
+        let hand = parse_hand("J22JJ", true).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::FiveKind);
 
-        let hand = parse_hand("JJJJJ", true);
+        let hand = parse_hand("JJJJJ", true).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::FiveKind);
 
-        let hand = parse_hand("2211J", true);
+        let hand = parse_hand("2211J", true).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::FullHouse);
 
-        let hand = parse_hand("88388", true);
+        let hand = parse_hand("88388", true).unwrap();
         assert_eq!(get_hand_kind(&hand.cards), HandKind::FourKind);
     }
 
     #[test]
     fn hand_ordering_joker_test() {
-        let small = parse_hand("J2345", true);
-        let big = parse_hand("22345", true);
+        let small = parse_hand("J2345", true).unwrap();
+        let big = parse_hand("22345", true).unwrap();
         #[rustfmt::skip] let str = if small < big { "".to_string() } else { format!("{:?} !< {:?}", small, big) };
         assert_eq!(str, "");
         #[rustfmt::skip] let str = if big > small { "".to_string() } else { format!("{:?} !< {:?}", small, big) };
         assert_eq!(str, "");
 
-        let small = parse_hand("12111", true);
-        let big = parse_hand("13J11", true);
+        let small = parse_hand("12111", true).unwrap();
+        let big = parse_hand("13J11", true).unwrap();
         #[rustfmt::skip] let str = if small < big { "".to_string() } else { format!("{:?} !< {:?}", small, big) };
         assert_eq!(str, "");
         #[rustfmt::skip] let str = if big > small { "".to_string() } else { format!("{:?} !< {:?}", small, big) };
         assert_eq!(str, "");
 
-        let small = parse_hand("1J111", true);
-        let big = parse_hand("11111", true);
+        let small = parse_hand("1J111", true).unwrap();
+        let big = parse_hand("11111", true).unwrap();
         #[rustfmt::skip] let str = if small < big { "".to_string() } else { format!("{:?} !< {:?}", small, big) };
         assert_eq!(str, "");
         #[rustfmt::skip] let str = if big > small { "".to_string() } else { format!("{:?} !< {:?}", small, big) };
         assert_eq!(str, "");
 
-        let small = parse_hand("11221", true);
-        let big = parse_hand("11221", true);
+        let small = parse_hand("11221", true).unwrap();
+        let big = parse_hand("11221", true).unwrap();
         #[rustfmt::skip] let str = if small == big { "".to_string() } else { format!("{:?} == {:?}", small, big) };
         assert_eq!(str, "");
         #[rustfmt::skip] let str = if big == small { "".to_string() } else { format!("{:?} == {:?}", small, big) };
         assert_eq!(str, "");
     }
-
-    #[test]
-    fn part2_sample_test() {
-        let lines = get_resource_lines(&resource_path!("sample.txt").unwrap());
-        let mut hands = parse_problem(&lines, true);
-        hands.sort();
-
-        let value = hands
-            .iter()
-            .enumerate()
-            .fold(0i32, |acc, (idx, h)| acc + ((idx as i32 + 1) * h.bet));
-
-        assert_eq!(value, 5905);
-    }
-
-    #[test]
-    fn part2_main_test() {
-        let lines = get_resource_lines(&resource_path!("main.txt").unwrap());
-        let mut hands = parse_problem(&lines, true);
-        hands.sort();
-
-        let value = hands
-            .iter()
-            .enumerate()
-            .fold(0i32, |acc, (idx, h)| acc + ((idx as i32 + 1) * h.bet));
-
-        assert_eq!(value, 245576185);
-    }
 }